@@ -0,0 +1,183 @@
+use std::{
+    fmt::Display,
+    io::{self, BufRead, Write},
+    sync::mpsc::{self, Receiver, TryRecvError},
+    thread,
+    time::Duration,
+};
+
+/// Receives the states of a step-wise simulation as it runs.
+pub trait Animator {
+    /// Shows the state after a step. Returns `false` once the viewer asked
+    /// to stop, so that the simulation can end early.
+    fn frame(&mut self, caption: &str, state: &dyn Display) -> bool;
+}
+
+/// Keeps every frame, caption first. Meant for tests.
+impl Animator for Vec<String> {
+    fn frame(&mut self, caption: &str, state: &dyn Display) -> bool {
+        self.push(format!("{}\n{}", caption, state));
+        true
+    }
+}
+
+const CLEAR_SCREEN: &str = "\x1b[2J";
+const CURSOR_HOME: &str = "\x1b[H";
+const CLEAR_TO_END: &str = "\x1b[J";
+
+const HELP: &str = "Enter: pause / step   c: continue   q: quit";
+
+/// Plays frames in a terminal, redrawing in place with ANSI escape codes.
+/// Lines read from `controls` steer the playback: an empty line pauses,
+/// or steps one frame when paused; `c` continues and `q` quits.
+pub struct Terminal<W: Write> {
+    out: W,
+    delay: Duration,
+    controls: Receiver<String>,
+    paused: bool,
+    frames: usize,
+}
+
+impl<W: Write> Terminal<W> {
+    pub fn new(out: W, delay: Duration, controls: Receiver<String>) -> Self {
+        Self {
+            out,
+            delay,
+            controls,
+            paused: false,
+            frames: 0,
+        }
+    }
+
+    /// Waits after a frame as the controls say, returning whether to go on.
+    fn wait(&mut self) -> bool {
+        if self.paused {
+            return match self.controls.recv() {
+                Ok(line) => self.control(line.trim()),
+                // Nobody is left to unpause, so play the rest.
+                Err(_) => {
+                    self.paused = false;
+                    true
+                }
+            };
+        }
+
+        // One command per frame, so that a pause typed ahead still lets
+        // the frames before it play.
+        thread::sleep(self.delay);
+        match self.controls.try_recv() {
+            Ok(line) => self.control(line.trim()),
+            Err(TryRecvError::Empty | TryRecvError::Disconnected) => true,
+        }
+    }
+
+    fn control(&mut self, command: &str) -> bool {
+        match command {
+            "q" => false,
+            "c" => {
+                self.paused = false;
+                true
+            }
+            "" => {
+                self.paused = true;
+                true
+            }
+            _ => true,
+        }
+    }
+}
+
+impl<W: Write> Animator for Terminal<W> {
+    fn frame(&mut self, caption: &str, state: &dyn Display) -> bool {
+        if self.frames == 0 {
+            let _ = write!(self.out, "{}", CLEAR_SCREEN);
+        }
+        self.frames += 1;
+
+        let status = if self.paused { "paused" } else { "playing" };
+        let written = write!(
+            self.out,
+            "{}{}\n{}\n{} ({}, frame {})\n{}",
+            CURSOR_HOME,
+            caption,
+            state.to_string().trim_end(),
+            HELP,
+            status,
+            self.frames,
+            CLEAR_TO_END
+        )
+        .and_then(|_| self.out.flush());
+
+        // Stop if the terminal went away, rather than simulate unseen.
+        written.is_ok() && self.wait()
+    }
+}
+
+/// The lines typed on stdin, read in the background so that playback
+/// doesn't block on them.
+pub fn stdin_controls() -> Receiver<String> {
+    let (sender, receiver) = mpsc::channel();
+
+    thread::spawn(move || {
+        for line in io::stdin().lock().lines() {
+            let Ok(line) = line else { break };
+            if sender.send(line).is_err() {
+                break;
+            }
+        }
+    });
+
+    receiver
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn play(commands: &[&str], frames: usize) -> (String, usize) {
+        let (sender, receiver) = mpsc::channel();
+        commands
+            .iter()
+            .for_each(|command| sender.send(command.to_string()).unwrap());
+        drop(sender);
+
+        let mut out = vec![];
+        let mut terminal = Terminal::new(&mut out, Duration::ZERO, receiver);
+        let shown = (0..frames)
+            .take_while(|frame| terminal.frame(&format!("step {}", frame), &"#.#"))
+            .count();
+
+        (String::from_utf8(out).unwrap(), shown)
+    }
+
+    #[test]
+    fn test_terminal() {
+        let (out, shown) = play(&[], 3);
+
+        assert_eq!(shown, 3);
+        assert!(out.starts_with("\x1b[2J\x1b[Hstep 0\n#.#\n"));
+        assert_eq!(out.matches(CURSOR_HOME).count(), 3);
+        assert!(out.contains("(playing, frame 3)"));
+    }
+
+    #[test]
+    fn test_terminal_controls() {
+        // Pause after the first frame, step once, continue, then quit.
+        let (out, shown) = play(&["", "", "c", "q"], 10);
+        assert!(out.contains("(playing, frame 1)"));
+        assert!(out.contains("(paused, frame 2)"));
+        assert!(out.contains("(paused, frame 3)"));
+        assert!(out.contains("(playing, frame 4)"));
+        assert_eq!(shown, 3);
+
+        let (_, shown) = play(&["q"], 10);
+        assert_eq!(shown, 0);
+    }
+
+    #[test]
+    fn test_recorder() {
+        let mut frames = vec![];
+        assert!(frames.frame("start", &42));
+        assert_eq!(frames, ["start\n42"]);
+    }
+}
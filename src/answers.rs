@@ -0,0 +1,336 @@
+use std::{
+    fmt, fs,
+    path::{Path, PathBuf},
+};
+
+use crate::{input, Part};
+
+/// Environment variable overriding the location of the answers file.
+pub const ANSWERS_ENV: &str = "AOC_ANSWERS";
+
+const FILE_NAME: &str = "answers.txt";
+
+/// Answers for the maintainer's inputs, checked when the answers file has
+/// none for an input.
+const SEED: &str = include_str!("../seed_answers.txt");
+
+/// FNV-1a hash of the trimmed input. Stable across platforms and compiler
+/// versions, unlike `DefaultHasher`.
+pub fn checksum(input: &str) -> u64 {
+    input.trim().bytes().fold(0xcbf29ce484222325, |hash, byte| {
+        (hash ^ byte as u64).wrapping_mul(0x100000001b3)
+    })
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Entry {
+    pub year: u32,
+    pub day: u32,
+    pub part: Part,
+    /// The checksum of the input the answer is for, or `None` for any
+    /// input of the day.
+    pub checksum: Option<u64>,
+    pub answer: String,
+}
+
+impl fmt::Display for Entry {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} {:02} {} ", self.year, self.day, self.part.number())?;
+        match self.checksum {
+            Some(checksum) => write!(f, "{:016x}", checksum)?,
+            None => f.write_str("-")?,
+        }
+        write!(f, " {}", self.answer)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Verdict {
+    Pass,
+    Fail { expected: String },
+    Unknown,
+}
+
+/// Known answers, one per line as `<year> <day> <part> <checksum> <answer>`,
+/// where a checksum of `-` matches any input. Blank lines and `#` comments
+/// are ignored.
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct Answers {
+    entries: Vec<Entry>,
+    /// Checked after `entries`, but never saved with them.
+    seed: Vec<Entry>,
+}
+
+impl Answers {
+    pub fn parse(content: &str) -> Result<Self, String> {
+        let entries = content
+            .lines()
+            .enumerate()
+            .filter(|(_, line)| !line.trim().is_empty() && !line.trim().starts_with('#'))
+            .map(|(index, line)| {
+                let invalid = || format!("invalid answers line {}: '{}'", index + 1, line);
+                let mut fields = line.trim().splitn(5, ' ');
+                let mut field = || fields.next().ok_or_else(invalid);
+
+                Ok(Entry {
+                    year: field()?.parse().map_err(|_| invalid())?,
+                    day: field()?.parse().map_err(|_| invalid())?,
+                    part: match field()? {
+                        "1" => Part::One,
+                        "2" => Part::Two,
+                        _ => return Err(invalid()),
+                    },
+                    checksum: match field()? {
+                        "-" => None,
+                        checksum => Some(u64::from_str_radix(checksum, 16).map_err(|_| invalid())?),
+                    },
+                    answer: field()?.trim().to_string(),
+                })
+            })
+            .collect::<Result<_, _>>()?;
+
+        Ok(Self {
+            entries,
+            seed: vec![],
+        })
+    }
+
+    /// Loads the answers file, or starts an empty one if it does not exist
+    /// yet, backed by the seed answers shipped with the crate.
+    pub fn load(path: &Path) -> Result<Self, String> {
+        let mut answers = if path.exists() {
+            fs::read_to_string(path)
+                .map_err(|err| format!("cannot read {}: {}", path.display(), err))
+                .and_then(|content| Self::parse(&content))?
+        } else {
+            Self::default()
+        };
+
+        answers.seed = Self::parse(SEED)?.entries;
+        Ok(answers)
+    }
+
+    pub fn save(&self, path: &Path) -> Result<(), String> {
+        fs::write(path, self.to_string())
+            .map_err(|err| format!("cannot write {}: {}", path.display(), err))
+    }
+
+    /// The answer for the input with `checksum`, preferring one recorded
+    /// for that very input over one for any input. Seed answers for any
+    /// input are left out: they only hold for the maintainer's inputs, see
+    /// [`Answers::check`].
+    pub fn get(&self, year: u32, day: u32, part: Part, checksum: u64) -> Option<&str> {
+        [
+            (&self.entries, Some(checksum)),
+            (&self.entries, None),
+            (&self.seed, Some(checksum)),
+        ]
+        .into_iter()
+        .find_map(|(entries, checksum)| {
+            entries.iter().find(|entry| {
+                entry.year == year
+                    && entry.day == day
+                    && entry.part == part
+                    && entry.checksum == checksum
+            })
+        })
+        .map(|entry| entry.answer.as_str())
+    }
+
+    /// Records `answer`, unless an answer for the same input is already
+    /// known.
+    pub fn insert(&mut self, entry: Entry) -> bool {
+        let known = match entry.checksum {
+            Some(checksum) => self
+                .get(entry.year, entry.day, entry.part, checksum)
+                .is_some(),
+            None => self.entries.iter().any(|known| {
+                (known.year, known.day, known.part, known.checksum)
+                    == (entry.year, entry.day, entry.part, None)
+            }),
+        };
+        if known {
+            false
+        } else {
+            self.entries.push(entry);
+            true
+        }
+    }
+
+    /// Checks `answer` against the known one. A seed answer for any input
+    /// can only pass: a different answer may well be for a different
+    /// input, so it is unknown rather than a failure.
+    pub fn check(&self, year: u32, day: u32, part: Part, input: &str, answer: &str) -> Verdict {
+        let seed = self.seed.iter().find(|entry| {
+            (entry.year, entry.day, entry.part, entry.checksum) == (year, day, part, None)
+        });
+
+        match self.get(year, day, part, checksum(input)) {
+            Some(expected) if expected == answer => Verdict::Pass,
+            Some(expected) => Verdict::Fail {
+                expected: expected.to_string(),
+            },
+            None if seed.is_some_and(|seed| seed.answer == answer) => Verdict::Pass,
+            None => Verdict::Unknown,
+        }
+    }
+}
+
+impl fmt::Display for Answers {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "# year day part checksum answer")?;
+        self.entries
+            .iter()
+            .try_for_each(|entry| writeln!(f, "{}", entry))
+    }
+}
+
+/// Resolves the answers file: `AOC_ANSWERS`, then the first input root that
+/// already has an `answers.txt`, then `answers.txt` in the first input root.
+pub fn default_path() -> PathBuf {
+    if let Some(path) = std::env::var_os(ANSWERS_ENV) {
+        return PathBuf::from(path);
+    }
+
+    let roots = input::roots();
+    roots
+        .iter()
+        .map(|root| root.join(FILE_NAME))
+        .find(|path| path.is_file())
+        .unwrap_or_else(|| roots[0].join(FILE_NAME))
+}
+
+/// Used by the `test_pN_actual` tests: checks `solve` against the recorded
+/// answer for the local input. Skips when the input or its answer is
+/// missing, saying how to record the answer in the latter case.
+#[cfg(test)]
+pub fn assert_actual(year: u32, day: u32, part: Part, solve: impl Fn(&str) -> String) {
+    let Some(input) = input::actual_input(year, day) else {
+        return;
+    };
+
+    let answers = Answers::load(&default_path()).unwrap();
+    let answer = solve(&input);
+    match answers.check(year, day, part, &input, &answer) {
+        Verdict::Pass => {}
+        Verdict::Fail { expected } => assert_eq!(answer, expected),
+        Verdict::Unknown => eprintln!(
+            "skipped {} day {:02} part {}: no answer known, record it with verify --record",
+            year,
+            day,
+            part.number()
+        ),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SAMPLE_ANSWERS: &str = r"
+# year day part checksum answer
+2023 01 1 00000000000000ff 142
+2023 01 2 00000000000000ff 281
+
+2023 21 2 0000000000000001 not yet solved
+2023 22 1 - 5
+2023 22 1 0000000000000002 6
+";
+
+    #[test]
+    fn test_checksum() {
+        assert_eq!(checksum(""), 0xcbf29ce484222325);
+        assert_eq!(checksum("a"), 0xaf63dc4c8601ec8c);
+        assert_eq!(checksum("1abc2\n"), checksum("\n1abc2"));
+        assert_ne!(checksum("1abc2"), checksum("1abc3"));
+    }
+
+    #[test]
+    fn test_parse() {
+        let answers = Answers::parse(SAMPLE_ANSWERS).unwrap();
+
+        assert_eq!(answers.get(2023, 1, Part::One, 0xff), Some("142"));
+        assert_eq!(answers.get(2023, 1, Part::Two, 0xff), Some("281"));
+        assert_eq!(answers.get(2023, 1, Part::One, 0xfe), None);
+        assert_eq!(answers.get(2022, 1, Part::One, 0xff), None);
+        assert_eq!(answers.get(2023, 21, Part::Two, 1), Some("not yet solved"));
+        assert_eq!(answers.get(2023, 22, Part::One, 1), Some("5"));
+        assert_eq!(answers.get(2023, 22, Part::One, 2), Some("6"));
+    }
+
+    #[test]
+    fn test_seed() {
+        let seed = Answers::parse(SEED).unwrap();
+        assert!(seed.entries.iter().all(|entry| entry.checksum.is_none()));
+
+        let mut answers = Answers::load(Path::new("no/such/answers.txt")).unwrap();
+        assert_eq!(answers.to_string(), "# year day part checksum answer\n");
+        assert_eq!(answers.get(2023, 1, Part::Two, 0xff), None);
+        assert_eq!(
+            answers.check(2023, 1, Part::Two, "1abc2", "54418"),
+            Verdict::Pass
+        );
+
+        // Another answer is likely for another input, and can be recorded.
+        assert_eq!(
+            answers.check(2023, 1, Part::Two, "1abc2", "12"),
+            Verdict::Unknown
+        );
+        assert!(answers.insert(Entry {
+            year: 2023,
+            day: 1,
+            part: Part::Two,
+            checksum: Some(checksum("1abc2")),
+            answer: "12".to_string(),
+        }));
+        assert_eq!(
+            answers.check(2023, 1, Part::Two, "1abc2", "54418"),
+            Verdict::Fail {
+                expected: "12".to_string()
+            }
+        );
+    }
+
+    #[test]
+    fn test_parse_invalid() {
+        assert!(Answers::parse("2023 01 3 ff 1").is_err());
+        assert!(Answers::parse("2023 01 1 xyz 1").is_err());
+        assert!(Answers::parse("2023 01 1 ff").is_err());
+    }
+
+    #[test]
+    fn test_round_trip() {
+        let answers = Answers::parse(SAMPLE_ANSWERS).unwrap();
+        assert_eq!(Answers::parse(&answers.to_string()), Ok(answers));
+    }
+
+    #[test]
+    fn test_insert_and_check() {
+        let mut answers = Answers::default();
+        let input = "1abc2";
+        let entry = Entry {
+            year: 2023,
+            day: 1,
+            part: Part::One,
+            checksum: Some(checksum(input)),
+            answer: "12".to_string(),
+        };
+
+        assert_eq!(
+            answers.check(2023, 1, Part::One, input, "12"),
+            Verdict::Unknown
+        );
+        assert!(answers.insert(entry.clone()));
+        assert!(!answers.insert(entry));
+        assert_eq!(
+            answers.check(2023, 1, Part::One, input, "12"),
+            Verdict::Pass
+        );
+        assert_eq!(
+            answers.check(2023, 1, Part::One, input, "13"),
+            Verdict::Fail {
+                expected: "12".to_string()
+            }
+        );
+    }
+}
@@ -0,0 +1,286 @@
+use std::{
+    fmt,
+    hint::black_box,
+    time::{Duration, Instant},
+};
+
+use crate::{parse::ParseError, AnySolution, Part};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Stage {
+    Parse,
+    Part1,
+    Part2,
+}
+
+impl Stage {
+    pub const ALL: [Stage; 3] = [Stage::Parse, Stage::Part1, Stage::Part2];
+
+    fn name(&self) -> &'static str {
+        match self {
+            Stage::Parse => "parse",
+            Stage::Part1 => "part1",
+            Stage::Part2 => "part2",
+        }
+    }
+
+    fn from_name(name: &str) -> Option<Self> {
+        Stage::ALL.into_iter().find(|stage| stage.name() == name)
+    }
+}
+
+impl fmt::Display for Stage {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.name())
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Stats {
+    pub min: Duration,
+    pub median: Duration,
+    pub max: Duration,
+}
+
+impl Stats {
+    pub fn from_samples(mut samples: Vec<Duration>) -> Self {
+        assert!(!samples.is_empty(), "cannot compute stats of no samples");
+        samples.sort();
+
+        Self {
+            min: samples[0],
+            median: samples[samples.len() / 2],
+            max: samples[samples.len() - 1],
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Measurement {
+    pub year: u32,
+    pub day: u32,
+    pub stage: Stage,
+    pub iterations: usize,
+    pub stats: Stats,
+}
+
+fn time<T>(f: impl FnOnce() -> T) -> Duration {
+    let start = Instant::now();
+    black_box(f());
+    start.elapsed()
+}
+
+/// Times parsing and both parts of `solution` separately, `iterations`
+/// times each. Invalid input is reported before anything is timed.
+pub fn bench_day(
+    year: u32,
+    day: u32,
+    solution: &dyn AnySolution,
+    input: &str,
+    iterations: usize,
+) -> Result<Vec<Measurement>, ParseError> {
+    let parsed = solution.parse_any(input)?;
+    let mut samples = Stage::ALL.map(|_| Vec::with_capacity(iterations));
+
+    (0..iterations).for_each(|_| {
+        samples[0].push(time(|| solution.parse_any(input)));
+        samples[1].push(time(|| solution.solve_any(parsed.as_ref(), Part::One)));
+        samples[2].push(time(|| solution.solve_any(parsed.as_ref(), Part::Two)));
+    });
+
+    Ok(Stage::ALL
+        .into_iter()
+        .zip(samples)
+        .map(|(stage, samples)| Measurement {
+            year,
+            day,
+            stage,
+            iterations,
+            stats: Stats::from_samples(samples),
+        })
+        .collect())
+}
+
+const HEADER: &str = "# year day stage iterations min_ns median_ns max_ns";
+
+/// Serializes measurements in the `bench_output.txt` format, one line per
+/// year, day and stage.
+pub fn to_baseline(measurements: &[Measurement]) -> String {
+    measurements
+        .iter()
+        .fold(format!("{}\n", HEADER), |mut acc, measurement| {
+            acc.push_str(&format!(
+                "{} {:02} {} {} {} {} {}\n",
+                measurement.year,
+                measurement.day,
+                measurement.stage,
+                measurement.iterations,
+                measurement.stats.min.as_nanos(),
+                measurement.stats.median.as_nanos(),
+                measurement.stats.max.as_nanos(),
+            ));
+            acc
+        })
+}
+
+/// Reads back the output of [`to_baseline`]. Blank lines and `#` comments
+/// are ignored.
+pub fn parse_baseline(content: &str) -> Result<Vec<Measurement>, String> {
+    content
+        .lines()
+        .enumerate()
+        .filter(|(_, line)| !line.trim().is_empty() && !line.trim().starts_with('#'))
+        .map(|(index, line)| {
+            let invalid = || format!("invalid baseline line {}: '{}'", index + 1, line);
+            let fields = line.split_whitespace().collect::<Vec<_>>();
+
+            if fields.len() != 7 {
+                return Err(invalid());
+            }
+
+            let nanos = |field: &str| {
+                field
+                    .parse::<u64>()
+                    .map(Duration::from_nanos)
+                    .map_err(|_| invalid())
+            };
+
+            Ok(Measurement {
+                year: fields[0].parse().map_err(|_| invalid())?,
+                day: fields[1].parse().map_err(|_| invalid())?,
+                stage: Stage::from_name(fields[2]).ok_or_else(invalid)?,
+                iterations: fields[3].parse().map_err(|_| invalid())?,
+                stats: Stats {
+                    min: nanos(fields[4])?,
+                    median: nanos(fields[5])?,
+                    max: nanos(fields[6])?,
+                },
+            })
+        })
+        .collect()
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Regression {
+    pub year: u32,
+    pub day: u32,
+    pub stage: Stage,
+    pub baseline: Duration,
+    pub current: Duration,
+    pub percent: f64,
+}
+
+/// Compares medians against `baseline` and returns every measurement that
+/// got slower by more than `threshold_percent`.
+pub fn find_regressions(
+    current: &[Measurement],
+    baseline: &[Measurement],
+    threshold_percent: f64,
+) -> Vec<Regression> {
+    current
+        .iter()
+        .filter_map(|measurement| {
+            let previous = baseline.iter().find(|previous| {
+                (previous.year, previous.day, previous.stage)
+                    == (measurement.year, measurement.day, measurement.stage)
+            })?;
+
+            let baseline = previous.stats.median.as_nanos() as f64;
+            let current = measurement.stats.median.as_nanos() as f64;
+            let percent = if baseline == 0.0 {
+                0.0
+            } else {
+                (current - baseline) / baseline * 100.0
+            };
+
+            (percent > threshold_percent).then_some(Regression {
+                year: measurement.year,
+                day: measurement.day,
+                stage: measurement.stage,
+                baseline: previous.stats.median,
+                current: measurement.stats.median,
+                percent,
+            })
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn measurement(day: u32, stage: Stage, median_ms: u64) -> Measurement {
+        Measurement {
+            year: 2023,
+            day,
+            stage,
+            iterations: 3,
+            stats: Stats {
+                min: Duration::from_millis(median_ms - 1),
+                median: Duration::from_millis(median_ms),
+                max: Duration::from_millis(median_ms + 1),
+            },
+        }
+    }
+
+    #[test]
+    fn test_stats() {
+        assert_eq!(
+            Stats::from_samples(vec![
+                Duration::from_millis(5),
+                Duration::from_millis(1),
+                Duration::from_millis(9),
+                Duration::from_millis(3),
+                Duration::from_millis(4),
+            ]),
+            Stats {
+                min: Duration::from_millis(1),
+                median: Duration::from_millis(4),
+                max: Duration::from_millis(9),
+            }
+        );
+    }
+
+    #[test]
+    fn test_baseline_round_trip() {
+        let measurements = vec![
+            measurement(1, Stage::Parse, 2),
+            measurement(1, Stage::Part1, 10),
+            measurement(12, Stage::Part2, 300),
+        ];
+        let content = to_baseline(&measurements);
+
+        assert!(content.starts_with(HEADER));
+        assert!(content.contains("\n2023 12 part2 3 299000000 300000000 301000000\n"));
+        assert_eq!(parse_baseline(&content), Ok(measurements));
+    }
+
+    #[test]
+    fn test_parse_baseline_invalid() {
+        assert!(parse_baseline("2023 01 parse 3 1 2").is_err());
+        assert!(parse_baseline("2023 01 solve 3 1 2 3").is_err());
+        assert!(parse_baseline("2023 xx parse 3 1 2 3").is_err());
+        assert!(parse_baseline("01 parse 3 1 2 3").is_err());
+    }
+
+    #[test]
+    fn test_find_regressions() {
+        let baseline = vec![
+            measurement(1, Stage::Parse, 100),
+            measurement(1, Stage::Part1, 100),
+            measurement(2, Stage::Part1, 100),
+        ];
+        let current = vec![
+            measurement(1, Stage::Parse, 105),
+            measurement(1, Stage::Part1, 150),
+            measurement(2, Stage::Part1, 50),
+            measurement(3, Stage::Part1, 1000),
+        ];
+
+        let regressions = find_regressions(&current, &baseline, 10.0);
+        assert_eq!(regressions.len(), 1);
+        assert_eq!(regressions[0].day, 1);
+        assert_eq!(regressions[0].stage, Stage::Part1);
+        assert_eq!(regressions[0].percent, 50.0);
+    }
+}
@@ -0,0 +1,213 @@
+use std::{collections::HashMap, hash::Hash};
+
+/// How [`nth_state`] recognises a state it has seen before.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Method {
+    /// Remembers the key of every state, finding the cycle as soon as it
+    /// closes, at the cost of one key and one state per step.
+    Hashing,
+    /// Brent's algorithm, which keeps only two states at a time but steps
+    /// through the cycle a few more times.
+    Brent,
+}
+
+/// The shape of the sequence of states: after `start` steps it repeats
+/// every `period` steps.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Cycle {
+    pub start: usize,
+    pub period: usize,
+}
+
+impl Cycle {
+    /// The smallest step with the same state as step `n`.
+    pub fn reduce(&self, n: usize) -> usize {
+        if n < self.start {
+            n
+        } else {
+            self.start + (n - self.start) % self.period
+        }
+    }
+}
+
+/// The state after `n` applications of `step` to `initial`, skipping whole
+/// cycles once the sequence repeats. States are considered equal when their
+/// `key`s are.
+pub fn nth_state<S: Clone, K: Hash + Eq>(
+    initial: S,
+    n: usize,
+    method: Method,
+    mut step: impl FnMut(&S) -> S,
+    mut key: impl FnMut(&S) -> K,
+) -> S {
+    match method {
+        Method::Hashing => {
+            let mut seen = HashMap::new();
+            let mut history = vec![initial];
+
+            loop {
+                let index = history.len() - 1;
+                let current = &history[index];
+                if index == n {
+                    return history.swap_remove(index);
+                }
+
+                let current_key = key(current);
+                if let Some(&start) = seen.get(&current_key) {
+                    let cycle = Cycle {
+                        start,
+                        period: index - start,
+                    };
+                    return history.swap_remove(cycle.reduce(n));
+                }
+
+                seen.insert(current_key, index);
+                let next = step(current);
+                history.push(next);
+            }
+        }
+        Method::Brent => {
+            let cycle = match brent(&initial, n, &mut step, &mut key) {
+                Ok(cycle) => cycle,
+                Err(state) => return state,
+            };
+
+            (0..cycle.reduce(n)).fold(initial, |state, _| step(&state))
+        }
+    }
+}
+
+/// The cycle the states from `initial` run into, found with `method`. Does
+/// not return if they never repeat.
+pub fn find_cycle<S: Clone, K: Hash + Eq>(
+    initial: &S,
+    method: Method,
+    mut step: impl FnMut(&S) -> S,
+    mut key: impl FnMut(&S) -> K,
+) -> Cycle {
+    match method {
+        Method::Hashing => {
+            let mut seen = HashMap::new();
+            let mut state = initial.clone();
+
+            (0..)
+                .find_map(|index| {
+                    let start = *seen.entry(key(&state)).or_insert(index);
+                    state = step(&state);
+                    (start != index).then_some(Cycle {
+                        start,
+                        period: index - start,
+                    })
+                })
+                .unwrap()
+        }
+        Method::Brent => match brent(initial, usize::MAX, &mut step, &mut key) {
+            Ok(cycle) => cycle,
+            Err(_) => unreachable!("no cycle within usize::MAX steps"),
+        },
+    }
+}
+
+/// Brent's cycle detection. Gives up with the state at step `limit` if
+/// that is reached before the period is known.
+fn brent<S: Clone, K: Eq>(
+    initial: &S,
+    limit: usize,
+    step: &mut impl FnMut(&S) -> S,
+    key: &mut impl FnMut(&S) -> K,
+) -> Result<Cycle, S> {
+    // Find the period: the hare runs ahead in windows of growing powers of
+    // two until it meets the tortoise waiting at the start of the window.
+    let mut power = 1;
+    let mut period = 1;
+    let mut tortoise = key(initial);
+    let mut hare = step(initial);
+    let mut steps = 1;
+
+    loop {
+        if steps >= limit {
+            return Err(if limit == 0 { initial.clone() } else { hare });
+        }
+        if key(&hare) == tortoise {
+            break;
+        }
+        if power == period {
+            tortoise = key(&hare);
+            power *= 2;
+            period = 0;
+        }
+        hare = step(&hare);
+        period += 1;
+        steps += 1;
+    }
+
+    // Find the start: with the hare one period ahead, both meet at the
+    // first repeated state.
+    let mut tortoise = initial.clone();
+    let mut hare = (0..period).fold(initial.clone(), |state, _| step(&state));
+    let mut start = 0;
+
+    while key(&tortoise) != key(&hare) {
+        tortoise = step(&tortoise);
+        hare = step(&hare);
+        start += 1;
+    }
+
+    Ok(Cycle { start, period })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// 0, 1, 2, 3, then 4..=9 over and over.
+    fn step(state: &u32) -> u32 {
+        if *state == 9 {
+            4
+        } else {
+            state + 1
+        }
+    }
+
+    #[test]
+    fn test_find_cycle() {
+        let expected = Cycle {
+            start: 4,
+            period: 6,
+        };
+
+        assert_eq!(find_cycle(&0, Method::Hashing, step, |s| *s), expected);
+        assert_eq!(find_cycle(&0, Method::Brent, step, |s| *s), expected);
+        assert_eq!(
+            find_cycle(&5, Method::Brent, step, |s| *s),
+            Cycle {
+                start: 0,
+                period: 6
+            }
+        );
+    }
+
+    #[test]
+    fn test_nth_state() {
+        let brute = |n: usize| (0..n).fold(0, |state, _| step(&state));
+
+        [Method::Hashing, Method::Brent]
+            .into_iter()
+            .for_each(|method| {
+                (0..40).for_each(|n| {
+                    assert_eq!(nth_state(0, n, method, step, |s| *s), brute(n), "{n}");
+                });
+                assert_eq!(nth_state(0, 1_000_000_003, method, step, |s| *s), 7);
+            });
+    }
+
+    #[test]
+    fn test_nth_state_without_cycle() {
+        // Never repeats, so only the first steps may be simulated.
+        assert_eq!(
+            nth_state(0u64, 100, Method::Hashing, |s| s + 1, |s| *s),
+            100
+        );
+        assert_eq!(nth_state(0u64, 100, Method::Brent, |s| s + 1, |s| *s), 100);
+    }
+}
@@ -0,0 +1,284 @@
+use std::{
+    fmt,
+    ops::{Index, IndexMut},
+};
+
+use crate::{
+    dir::{Dir4, Dir8},
+    parse::{self, ParseError},
+    vec2::Vec2,
+};
+
+/// A cell of a [`Grid`], as `(x, y)` with `(0, 0)` in the top-left corner.
+pub type Pos = (usize, usize);
+
+/// A dense, rectangular grid stored row by row.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Grid<T> {
+    width: usize,
+    height: usize,
+    cells: Vec<T>,
+}
+
+impl<T> Grid<T> {
+    /// Builds a grid from its rows, which must all have the same length.
+    pub fn from_rows(rows: Vec<Vec<T>>) -> Self {
+        let width = rows.first().map_or(0, Vec::len);
+        assert!(
+            rows.iter().all(|row| row.len() == width),
+            "grid rows must have the same length"
+        );
+
+        Self {
+            width,
+            height: rows.len(),
+            cells: rows.into_iter().flatten().collect(),
+        }
+    }
+
+    /// Parses the block of characters `text`, a slice of `input`, turning
+    /// each character into a cell with `tile`. See [`parse::grid`].
+    pub fn parse(
+        input: &str,
+        text: &str,
+        expected: &str,
+        tile: impl Fn(char) -> Option<T>,
+    ) -> Result<Self, ParseError> {
+        parse::grid(input, text, expected, tile).map(Self::from_rows)
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    /// The cell at `pos` if it lies inside the grid.
+    pub fn checked_pos(&self, pos: Vec2) -> Option<Pos> {
+        let pos = (usize::try_from(pos.x).ok()?, usize::try_from(pos.y).ok()?);
+        self.contains(pos).then_some(pos)
+    }
+
+    pub fn contains(&self, (x, y): Pos) -> bool {
+        x < self.width && y < self.height
+    }
+
+    pub fn get(&self, pos: Pos) -> Option<&T> {
+        self.contains(pos).then(|| &self[pos])
+    }
+
+    pub fn get_mut(&mut self, pos: Pos) -> Option<&mut T> {
+        if self.contains(pos) {
+            Some(&mut self[pos])
+        } else {
+            None
+        }
+    }
+
+    /// Every position, row by row.
+    pub fn positions(&self) -> impl Iterator<Item = Pos> {
+        let width = self.width;
+        (0..self.height).flat_map(move |y| (0..width).map(move |x| (x, y)))
+    }
+
+    /// Every cell with its position, row by row.
+    pub fn iter(&self) -> impl Iterator<Item = (Pos, &T)> {
+        self.positions().zip(&self.cells)
+    }
+
+    /// The position of the first cell, row by row, matching `predicate`.
+    pub fn find(&self, predicate: impl Fn(&T) -> bool) -> Option<Pos> {
+        self.iter()
+            .find(|(_, cell)| predicate(cell))
+            .map(|(pos, _)| pos)
+    }
+
+    pub fn row(&self, y: usize) -> &[T] {
+        &self.cells[y * self.width..(y + 1) * self.width]
+    }
+
+    pub fn rows(&self) -> impl DoubleEndedIterator<Item = &[T]> + ExactSizeIterator {
+        self.cells.chunks(self.width.max(1))
+    }
+
+    pub fn column(&self, x: usize) -> impl Iterator<Item = &T> {
+        assert!(x < self.width, "column {} out of bounds", x);
+        self.cells.iter().skip(x).step_by(self.width)
+    }
+
+    /// The up to 4 orthogonal neighbours of `pos` inside the grid, in
+    /// [`Dir4::ALL`] order.
+    pub fn neighbours4(&self, pos: Pos) -> impl Iterator<Item = Pos> + '_ {
+        Dir4::ALL
+            .into_iter()
+            .filter_map(move |dir| self.checked_pos(Vec2::from(pos) + dir.offset()))
+    }
+
+    /// The up to 8 orthogonal and diagonal neighbours of `pos` inside the
+    /// grid, in [`Dir8::ALL`] order.
+    pub fn neighbours8(&self, pos: Pos) -> impl Iterator<Item = Pos> + '_ {
+        Dir8::ALL
+            .into_iter()
+            .filter_map(move |dir| self.checked_pos(Vec2::from(pos) + dir.offset()))
+    }
+
+    pub fn map<U>(&self, f: impl Fn(&T) -> U) -> Grid<U> {
+        Grid {
+            width: self.width,
+            height: self.height,
+            cells: self.cells.iter().map(f).collect(),
+        }
+    }
+}
+
+impl<T: Clone> Grid<T> {
+    pub fn new(width: usize, height: usize, fill: T) -> Self {
+        Self {
+            width,
+            height,
+            cells: vec![fill; width * height],
+        }
+    }
+
+    /// The grid mirrored along its main diagonal, so that rows become
+    /// columns.
+    pub fn transpose(&self) -> Self {
+        Self {
+            width: self.height,
+            height: self.width,
+            cells: (0..self.width)
+                .flat_map(|x| self.column(x).cloned())
+                .collect(),
+        }
+    }
+
+    /// The grid rotated a quarter turn clockwise: the left column becomes
+    /// the top row.
+    pub fn rotate_clockwise(&self) -> Self {
+        Self {
+            width: self.height,
+            height: self.width,
+            cells: (0..self.width)
+                .flat_map(|x| (0..self.height).rev().map(move |y| self[(x, y)].clone()))
+                .collect(),
+        }
+    }
+}
+
+impl<T> Index<Pos> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, (x, y): Pos) -> &T {
+        assert!(self.contains((x, y)), "({}, {}) out of bounds", x, y);
+        &self.cells[y * self.width + x]
+    }
+}
+
+impl<T> IndexMut<Pos> for Grid<T> {
+    fn index_mut(&mut self, (x, y): Pos) -> &mut T {
+        assert!(self.contains((x, y)), "({}, {}) out of bounds", x, y);
+        &mut self.cells[y * self.width + x]
+    }
+}
+
+/// One line per row, with no separator between cells.
+impl<T: fmt::Display> fmt::Display for Grid<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.rows().try_for_each(|row| {
+            row.iter().try_for_each(|cell| write!(f, "{}", cell))?;
+            writeln!(f)
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const INPUT: &str = "abc\ndef";
+
+    fn grid() -> Grid<char> {
+        Grid::parse(INPUT, INPUT, "a letter", |ch| {
+            ch.is_alphabetic().then_some(ch)
+        })
+        .unwrap()
+    }
+
+    #[test]
+    fn test_parse() {
+        let grid = grid();
+
+        assert_eq!((grid.width(), grid.height()), (3, 2));
+        assert_eq!(grid[(2, 1)], 'f');
+        assert_eq!(grid.to_string(), "abc\ndef\n");
+
+        let error = Grid::parse("ab\nc1", "ab\nc1", "a letter", |ch| {
+            ch.is_alphabetic().then_some(ch)
+        })
+        .unwrap_err();
+        assert_eq!((error.line, error.column), (2, 2));
+    }
+
+    #[test]
+    fn test_get() {
+        let mut grid = grid();
+
+        assert_eq!(grid.get((0, 1)), Some(&'d'));
+        assert_eq!(grid.get((3, 0)), None);
+        assert_eq!(grid.get((0, 2)), None);
+        assert_eq!(grid.checked_pos(Vec2::new(-1, 0)), None);
+        assert_eq!(grid.checked_pos(Vec2::new(1, 1)), Some((1, 1)));
+
+        *grid.get_mut((1, 0)).unwrap() = 'x';
+        assert_eq!(grid.row(0), ['a', 'x', 'c']);
+        assert!(grid.get_mut((1, 5)).is_none());
+    }
+
+    #[test]
+    fn test_neighbours() {
+        let grid = grid();
+
+        assert_eq!(
+            grid.neighbours4((0, 0)).collect::<Vec<_>>(),
+            [(1, 0), (0, 1)]
+        );
+        assert_eq!(
+            grid.neighbours8((1, 0)).collect::<Vec<_>>(),
+            [(2, 0), (2, 1), (1, 1), (0, 1), (0, 0)]
+        );
+        assert_eq!(grid.neighbours8((1, 1)).count(), 5);
+    }
+
+    #[test]
+    fn test_views() {
+        let grid = grid();
+
+        assert_eq!(
+            grid.rows().collect::<Vec<_>>(),
+            [['a', 'b', 'c'], ['d', 'e', 'f']]
+        );
+        assert_eq!(grid.column(1).collect::<String>(), "be");
+        assert_eq!(grid.find(|ch| *ch == 'e'), Some((1, 1)));
+        assert_eq!(grid.find(|ch| *ch == 'z'), None);
+        assert_eq!(grid.iter().nth(4), Some(((1, 1), &'e')));
+        assert_eq!(
+            grid.map(|ch| ch.to_ascii_uppercase()).to_string(),
+            "ABC\nDEF\n"
+        );
+        assert_eq!(Grid::new(2, 1, '.').to_string(), "..\n");
+    }
+
+    #[test]
+    fn test_transpose_rotate() {
+        let grid = grid();
+
+        assert_eq!(grid.transpose().to_string(), "ad\nbe\ncf\n");
+        assert_eq!(grid.rotate_clockwise().to_string(), "da\neb\nfc\n");
+        assert_eq!(
+            (0..4).fold(grid.clone(), |grid, _| grid.rotate_clockwise()),
+            grid
+        );
+    }
+}
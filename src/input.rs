@@ -0,0 +1,154 @@
+use std::{
+    fmt, fs,
+    io::{self, IsTerminal, Read},
+    path::{Path, PathBuf},
+};
+
+/// Environment variable pointing at a directory laid out like the
+/// `actual_inputs` submodule, i.e. `<dir>/<year>/<NN>/input.txt`.
+pub const INPUT_DIR_ENV: &str = "AOC_INPUT_DIR";
+
+#[derive(Debug)]
+pub enum InputError {
+    Unreadable(PathBuf, io::Error),
+    Missing {
+        year: u32,
+        day: u32,
+        tried: Vec<PathBuf>,
+    },
+}
+
+impl fmt::Display for InputError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            InputError::Unreadable(path, err) => {
+                write!(f, "cannot read {}: {}", path.display(), err)
+            }
+            InputError::Missing { year, day, tried } => {
+                write!(f, "input for {} day {:02} not found, tried:", year, day)?;
+                tried
+                    .iter()
+                    .try_for_each(|path| write!(f, "\n    {}", path.display()))
+            }
+        }
+    }
+}
+
+/// Where the input of `day` of `year` lives under an input root.
+pub fn day_path(root: &Path, year: u32, day: u32) -> PathBuf {
+    root.join(year.to_string())
+        .join(format!("{:02}", day))
+        .join("input.txt")
+}
+
+/// Directories holding private inputs, in lookup order: `AOC_INPUT_DIR`,
+/// then the `actual_inputs` submodule.
+pub fn roots() -> Vec<PathBuf> {
+    std::env::var_os(INPUT_DIR_ENV)
+        .map(PathBuf::from)
+        .into_iter()
+        .chain([Path::new(env!("CARGO_MANIFEST_DIR")).join("actual_inputs")])
+        .collect()
+}
+
+fn candidates(year: u32, day: u32) -> Vec<PathBuf> {
+    roots()
+        .iter()
+        .map(|root| day_path(root, year, day))
+        .collect()
+}
+
+fn read_file(path: &Path) -> Result<String, InputError> {
+    fs::read_to_string(path).map_err(|err| InputError::Unreadable(path.to_path_buf(), err))
+}
+
+fn read_stdin() -> Option<String> {
+    let mut stdin = io::stdin();
+    if stdin.is_terminal() {
+        return None;
+    }
+
+    let mut input = String::new();
+    stdin.read_to_string(&mut input).ok()?;
+
+    if input.trim().is_empty() {
+        None
+    } else {
+        Some(input)
+    }
+}
+
+/// Resolves the puzzle input for `day` of `year` from `AOC_INPUT_DIR`, then
+/// from the `actual_inputs` submodule.
+pub fn actual(year: u32, day: u32) -> Result<String, InputError> {
+    let tried = candidates(year, day);
+
+    match tried.iter().find(|path| path.is_file()) {
+        Some(path) => read_file(path),
+        None => Err(InputError::Missing { year, day, tried }),
+    }
+}
+
+/// Resolves the puzzle input for `day` of `year` from an explicit path, then
+/// from piped stdin, then falls back to [`actual`].
+pub fn load(year: u32, day: u32, path: Option<&Path>) -> Result<String, InputError> {
+    match path {
+        Some(path) => read_file(path),
+        None => read_stdin().map(Ok).unwrap_or_else(|| actual(year, day)),
+    }
+}
+
+/// Used by the `test_pN_actual` tests, so that a checkout without the
+/// private inputs still builds and passes.
+#[cfg(test)]
+pub fn actual_input(year: u32, day: u32) -> Option<String> {
+    match actual(year, day) {
+        Ok(input) => Some(input),
+        Err(_) => {
+            println!("skipped: input missing");
+            None
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_day_path() {
+        assert_eq!(
+            day_path(Path::new("inputs"), 2022, 5),
+            Path::new("inputs")
+                .join("2022")
+                .join("05")
+                .join("input.txt")
+        );
+    }
+
+    #[test]
+    fn test_missing_reports_tried_paths() {
+        let err = InputError::Missing {
+            year: 2023,
+            day: 7,
+            tried: vec![PathBuf::from("a/input.txt"), PathBuf::from("b/input.txt")],
+        };
+        assert_eq!(
+            err.to_string(),
+            "input for 2023 day 07 not found, tried:\n    a/input.txt\n    b/input.txt"
+        );
+    }
+
+    #[test]
+    fn test_load_explicit_path() {
+        let path = std::env::temp_dir().join("aoc_test_load_explicit_path.txt");
+        fs::write(&path, "hello").unwrap();
+        assert_eq!(load(2023, 1, Some(&path)).unwrap(), "hello");
+        fs::remove_file(&path).unwrap();
+
+        assert!(matches!(
+            load(2023, 1, Some(&path)),
+            Err(InputError::Unreadable(..))
+        ));
+    }
+}
@@ -0,0 +1,253 @@
+use std::{cmp::Ordering, fmt, ops::Range};
+
+/// A set of integers, kept as sorted, disjoint and non-adjacent half-open
+/// ranges so that equal sets compare equal.
+#[derive(Debug, Default, Clone, PartialEq, Eq, Hash)]
+pub struct IntervalSet {
+    ranges: Vec<Range<u64>>,
+}
+
+impl IntervalSet {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// The normalised form of `ranges`: empty ranges dropped, the rest
+    /// sorted and merged wherever they overlap or touch.
+    fn normalise(mut ranges: Vec<Range<u64>>) -> Self {
+        ranges.retain(|range| !range.is_empty());
+        ranges.sort_by_key(|range| range.start);
+
+        let ranges = ranges
+            .into_iter()
+            .fold(Vec::<Range<u64>>::new(), |mut acc, range| {
+                match acc.last_mut() {
+                    Some(last) if range.start <= last.end => last.end = last.end.max(range.end),
+                    _ => acc.push(range),
+                }
+                acc
+            });
+
+        Self { ranges }
+    }
+
+    pub fn ranges(&self) -> &[Range<u64>] {
+        &self.ranges
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.ranges.is_empty()
+    }
+
+    /// How many integers the set holds.
+    pub fn count(&self) -> u64 {
+        self.ranges
+            .iter()
+            .map(|range| range.end - range.start)
+            .sum()
+    }
+
+    pub fn min(&self) -> Option<u64> {
+        self.ranges.first().map(|range| range.start)
+    }
+
+    pub fn max(&self) -> Option<u64> {
+        self.ranges.last().map(|range| range.end - 1)
+    }
+
+    pub fn contains(&self, value: u64) -> bool {
+        self.ranges
+            .binary_search_by(|range| {
+                if range.end <= value {
+                    Ordering::Less
+                } else if range.start > value {
+                    Ordering::Greater
+                } else {
+                    Ordering::Equal
+                }
+            })
+            .is_ok()
+    }
+
+    pub fn insert(&mut self, range: Range<u64>) {
+        let mut ranges = std::mem::take(&mut self.ranges);
+        ranges.push(range);
+        *self = Self::normalise(ranges);
+    }
+
+    pub fn union(&self, other: &IntervalSet) -> Self {
+        Self::normalise(self.ranges.iter().chain(&other.ranges).cloned().collect())
+    }
+
+    pub fn intersection(&self, other: &IntervalSet) -> Self {
+        let mut ranges = vec![];
+        let (mut left, mut right) = (
+            self.ranges.iter().peekable(),
+            other.ranges.iter().peekable(),
+        );
+
+        while let (Some(a), Some(b)) = (left.peek(), right.peek()) {
+            let overlap = a.start.max(b.start)..a.end.min(b.end);
+            if !overlap.is_empty() {
+                ranges.push(overlap);
+            }
+
+            if a.end < b.end {
+                left.next();
+            } else {
+                right.next();
+            }
+        }
+
+        Self { ranges }
+    }
+
+    /// The integers of `self` not in `other`. Removing the middle of a
+    /// range splits it in two.
+    pub fn difference(&self, other: &IntervalSet) -> Self {
+        let mut ranges = vec![];
+        let mut removed = other.ranges.iter().peekable();
+
+        for range in &self.ranges {
+            let mut start = range.start;
+
+            while let Some(cut) = removed.peek() {
+                if cut.end <= start {
+                    removed.next();
+                    continue;
+                }
+                if cut.start >= range.end {
+                    break;
+                }
+
+                if cut.start > start {
+                    ranges.push(start..cut.start);
+                }
+                start = cut.end;
+
+                if cut.end > range.end {
+                    break;
+                }
+                removed.next();
+            }
+
+            if start < range.end {
+                ranges.push(start..range.end);
+            }
+        }
+
+        Self { ranges }
+    }
+}
+
+impl From<Range<u64>> for IntervalSet {
+    fn from(range: Range<u64>) -> Self {
+        Self::normalise(vec![range])
+    }
+}
+
+impl FromIterator<Range<u64>> for IntervalSet {
+    fn from_iter<I: IntoIterator<Item = Range<u64>>>(ranges: I) -> Self {
+        Self::normalise(ranges.into_iter().collect())
+    }
+}
+
+/// Inclusive ranges separated by commas, like `1-5, 8, 10-12`.
+impl fmt::Display for IntervalSet {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.is_empty() {
+            return f.write_str("{}");
+        }
+
+        self.ranges
+            .iter()
+            .enumerate()
+            .try_for_each(|(index, range)| {
+                if index > 0 {
+                    f.write_str(", ")?;
+                }
+                match range.end - range.start {
+                    1 => write!(f, "{}", range.start),
+                    _ => write!(f, "{}-{}", range.start, range.end - 1),
+                }
+            })
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::single_range_in_vec_init)]
+mod tests {
+    use super::*;
+
+    fn set(ranges: &[Range<u64>]) -> IntervalSet {
+        ranges.iter().cloned().collect()
+    }
+
+    #[test]
+    fn test_normalise() {
+        let set = set(&[10..20, 5..5, 0..3, 15..25, 3..4, 30..31]);
+
+        assert_eq!(set.ranges(), [0..4, 10..25, 30..31]);
+        assert_eq!(set.count(), 4 + 15 + 1);
+        assert_eq!((set.min(), set.max()), (Some(0), Some(30)));
+        assert_eq!(set.to_string(), "0-3, 10-24, 30");
+        assert!(IntervalSet::new().is_empty());
+        assert_eq!(IntervalSet::from(7..7), IntervalSet::new());
+    }
+
+    #[test]
+    fn test_contains() {
+        let set = set(&[0..4, 10..25]);
+
+        assert!(set.contains(0));
+        assert!(set.contains(3));
+        assert!(!set.contains(4));
+        assert!(set.contains(24));
+        assert!(!set.contains(25));
+        assert!(!IntervalSet::new().contains(0));
+    }
+
+    #[test]
+    fn test_insert_union() {
+        let mut left = set(&[0..5, 20..30]);
+        left.insert(5..8);
+        assert_eq!(left.ranges(), [0..8, 20..30]);
+
+        let union = left.union(&set(&[7..21, 40..41]));
+        assert_eq!(union.ranges(), [0..30, 40..41]);
+    }
+
+    #[test]
+    fn test_intersection() {
+        let left = set(&[0..10, 20..30, 40..50]);
+
+        assert_eq!(
+            left.intersection(&set(&[5..25, 28..45])).ranges(),
+            [5..10, 20..25, 28..30, 40..45]
+        );
+        assert!(left.intersection(&set(&[10..20])).is_empty());
+        assert_eq!(left.intersection(&left), left);
+    }
+
+    #[test]
+    fn test_difference() {
+        let interval = set(&[10..21]);
+        let minus = |ranges: &[Range<u64>]| interval.difference(&set(ranges)).ranges().to_vec();
+
+        assert_eq!(minus(&[0..10]), [10..21]);
+        assert_eq!(minus(&[21..31]), [10..21]);
+        assert_eq!(minus(&[0..11]), [11..21]);
+        assert_eq!(minus(&[20..31]), [10..20]);
+        assert_eq!(minus(&[0..16]), [16..21]);
+        assert_eq!(minus(&[15..31]), [10..15]);
+        assert_eq!(minus(&[0..31]), []);
+        assert_eq!(minus(&[10..21]), []);
+        assert_eq!(minus(&[15..16]), [10..15, 16..21]);
+        assert_eq!(minus(&[12..14, 16..18]), [10..12, 14..16, 18..21]);
+
+        assert_eq!(
+            set(&[0..10, 20..30]).difference(&set(&[5..25])).ranges(),
+            [0..5, 25..30]
+        );
+    }
+}
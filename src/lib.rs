@@ -0,0 +1,24 @@
+pub mod animate;
+pub mod answers;
+pub mod bench;
+pub mod cycle;
+pub mod dir;
+pub mod grid;
+pub mod input;
+pub mod interval;
+pub mod math;
+pub mod parse;
+pub mod polygon;
+#[cfg(test)]
+mod prop;
+pub mod pulse;
+pub mod render;
+pub mod rng;
+pub mod scaffold;
+pub mod search;
+mod solution;
+pub mod trace;
+pub mod vec2;
+pub mod years;
+
+pub use solution::{Answer, AnySolution, Part, Solution};
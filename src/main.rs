@@ -1,3 +1,1064 @@
-fn main() {
-    println!("Hello, world!");
+use std::{
+    any::Any,
+    fs, io,
+    path::{Path, PathBuf},
+    process::ExitCode,
+    time::Duration,
+};
+
+use aoc_2023::{
+    animate::{self, Terminal},
+    answers::{self, Answers, Entry, Verdict},
+    bench, input,
+    parse::ParseError,
+    render::Canvas,
+    rng::Rng,
+    scaffold,
+    trace::Event,
+    years::{self, Day, Year},
+    Part,
+};
+
+const USAGE: &str = r"Usage:
+    aoc_2023 [--year YYYY] list
+    aoc_2023 [--year YYYY] run <day> [--part 1|2] [--input FILE] [--render FILE]
+                           [--animate [--delay MS]] [--explain] [--trace FILE]
+    aoc_2023 [--year YYYY] run-all
+    aoc_2023 [--year YYYY] bench [<day>...] [--iterations N] [--threshold PERCENT]
+                           [--baseline FILE] [--output FILE]
+    aoc_2023 [--year YYYY] verify [<day>...] [--answers FILE] [--record]
+    aoc_2023 [--year YYYY] new-day <day> [--sample FILE] [--answer1 X] [--answer2 Y]
+    aoc_2023 [--year YYYY] gen <day> [--size N] [--seed S]
+
+--year may appear anywhere. run, new-day and gen default to the latest year
+with solutions; the other commands cover every year unless one is given.
+
+Without --input, the input is read from stdin when piped, otherwise from
+$AOC_INPUT_DIR/<year>/<NN>/input.txt, then
+actual_inputs/<year>/<NN>/input.txt.
+
+--render also draws the solution, for the days that support it, as an SVG
+or PPM image depending on the file extension. --animate plays the
+simulation of the days that have one in the terminal first, waiting
+--delay milliseconds between frames (default 100). Press Enter to pause
+or step a frame, c and Enter to continue, q and Enter to quit.
+
+--explain prints what the solver does along the way, for the days that
+trace it, and --trace writes the same events to a file as JSON lines, to
+diff two implementations. Day 23 starts with its trail graph in Graphviz's
+DOT language, under the dot field of the graph event.
+
+bench times parsing and both parts (default 10 iterations), writes the
+results to bench_output.txt and flags medians that regressed by more than
+the threshold (default 10%) against the baseline, which defaults to the
+previous output file.
+
+verify checks every answer against the answers file, keyed by year, day,
+part and input checksum. The file is $AOC_ANSWERS if set, otherwise
+answers.txt next to the inputs, backed by seed_answers.txt for the
+maintainer's inputs. --record adds the answers that are not known yet.
+
+new-day creates src/years/y<year>/day<NN>.rs from src/years/template.rs
+and registers it in src/years/y<year>/mod.rs, creating and registering the
+year module first if needed. --sample and the expected sample answers
+pre-fill the sample tests.
+
+gen prints a random input for stress tests, which run can read from a
+pipe. --size scales it (default 100), as lines, grid rows or items
+depending on the day, and the same --seed (default 0) gives the same
+input.";
+
+const DEFAULT_BENCH_OUTPUT: &str = "bench_output.txt";
+const DEFAULT_FRAME_DELAY: Duration = Duration::from_millis(100);
+const DEFAULT_GEN_SIZE: usize = 100;
+
+fn parse_day(value: &str) -> Result<u32, String> {
+    value
+        .parse::<u32>()
+        .map_err(|_| format!("invalid day '{}'", value))
+}
+
+fn parse_year(value: &str) -> Result<u32, String> {
+    value
+        .parse::<u32>()
+        .ok()
+        .filter(|year| (2015..=9999).contains(year))
+        .ok_or(format!("invalid year '{}'", value))
+}
+
+fn parse_image_path(value: &str) -> Result<PathBuf, String> {
+    let path = PathBuf::from(value);
+    match path.extension().and_then(|extension| extension.to_str()) {
+        Some("svg" | "ppm") => Ok(path),
+        _ => Err(format!(
+            "cannot render '{}', expected a .svg or .ppm file",
+            value
+        )),
+    }
+}
+
+fn parse_part(value: &str) -> Result<Part, String> {
+    match value {
+        "1" => Ok(Part::One),
+        "2" => Ok(Part::Two),
+        _ => Err(format!("invalid part '{}', expected 1 or 2", value)),
+    }
+}
+
+#[derive(Debug, PartialEq)]
+enum Command {
+    List,
+    Run {
+        day: u32,
+        part: Option<Part>,
+        input: Option<PathBuf>,
+        render: Option<PathBuf>,
+        /// The delay between frames, if animating.
+        animate: Option<Duration>,
+        explain: bool,
+        trace: Option<PathBuf>,
+    },
+    RunAll,
+    Bench {
+        days: Vec<u32>,
+        iterations: usize,
+        threshold: f64,
+        baseline: Option<PathBuf>,
+        output: PathBuf,
+    },
+    Verify {
+        days: Vec<u32>,
+        answers: Option<PathBuf>,
+        record: bool,
+    },
+    NewDay {
+        day: u32,
+        sample: Option<PathBuf>,
+        answers: [Option<String>; 2],
+    },
+    Gen {
+        day: u32,
+        size: usize,
+        seed: u64,
+    },
+}
+
+/// A command and the year it applies to, given by `--year` anywhere on the
+/// command line.
+#[derive(Debug, PartialEq)]
+struct Cli {
+    year: Option<u32>,
+    command: Command,
+}
+
+impl Cli {
+    fn parse(args: impl Iterator<Item = String>) -> Result<Self, String> {
+        let mut args = args.collect::<Vec<_>>();
+        let mut year = None;
+
+        while let Some(index) = args.iter().position(|arg| arg == "--year") {
+            if index + 1 == args.len() {
+                return Err("missing value for --year".to_string());
+            }
+            if year.is_some() {
+                return Err("--year given more than once".to_string());
+            }
+
+            year = Some(parse_year(&args[index + 1])?);
+            args.drain(index..index + 2);
+        }
+
+        Ok(Self {
+            year,
+            command: Command::parse(args.into_iter())?,
+        })
+    }
+
+    fn execute(self) -> Result<(), String> {
+        self.command.execute(self.year)
+    }
+}
+
+/// The years a command covers: the given one, or all of them.
+fn select_years(year: Option<u32>) -> Result<Vec<&'static Year>, String> {
+    match year {
+        Some(year) => years::find(year)
+            .map(|year| vec![year])
+            .ok_or(format!("no solutions for {} yet", year)),
+        None => Ok(years::ALL.iter().collect()),
+    }
+}
+
+/// A solved day of the given year, or of the latest one.
+fn find_day(year: Option<u32>, day: u32) -> Result<(&'static Year, &'static Day), String> {
+    let year = match year {
+        Some(year) => select_years(Some(year))?[0],
+        None => years::latest(),
+    };
+    let day = year
+        .find(day)
+        .ok_or(format!("{} day {} is not solved yet", year.year, day))?;
+
+    Ok((year, day))
+}
+
+/// Every solved day of the selected years, restricted to `days` unless it
+/// is empty.
+fn select_days(year: Option<u32>, days: &[u32]) -> Result<Vec<(u32, &'static Day)>, String> {
+    Ok(select_years(year)?
+        .into_iter()
+        .flat_map(|year| year.days.iter().map(|day| (year.year, day)))
+        .filter(|(_, day)| days.is_empty() || days.contains(&day.day))
+        .collect())
+}
+
+impl Command {
+    fn parse(mut args: impl Iterator<Item = String>) -> Result<Self, String> {
+        let command = args.next().ok_or("missing command")?;
+
+        let command = match command.as_str() {
+            "list" => Command::List,
+            "run-all" => Command::RunAll,
+            "run" => {
+                let day = parse_day(&args.next().ok_or("missing day")?)?;
+                let mut part = None;
+                let mut input = None;
+                let mut render = None;
+                let mut animate = false;
+                let mut delay = None;
+                let mut explain = false;
+                let mut trace = None;
+
+                while let Some(flag) = args.next() {
+                    let mut value = || args.next().ok_or(format!("missing value for {}", flag));
+
+                    match flag.as_str() {
+                        "--part" => part = Some(parse_part(&value()?)?),
+                        "--input" => input = Some(PathBuf::from(value()?)),
+                        "--render" => render = Some(parse_image_path(&value()?)?),
+                        "--animate" => animate = true,
+                        "--explain" => explain = true,
+                        "--trace" => trace = Some(PathBuf::from(value()?)),
+                        "--delay" => {
+                            let value = value()?;
+                            delay = Some(Duration::from_millis(
+                                value
+                                    .parse::<u64>()
+                                    .map_err(|_| format!("invalid delay '{}'", value))?,
+                            ));
+                        }
+                        _ => return Err(format!("unknown flag '{}'", flag)),
+                    }
+                }
+
+                if delay.is_some() && !animate {
+                    return Err("--delay needs --animate".to_string());
+                }
+
+                Command::Run {
+                    day,
+                    part,
+                    input,
+                    render,
+                    animate: animate.then(|| delay.unwrap_or(DEFAULT_FRAME_DELAY)),
+                    explain,
+                    trace,
+                }
+            }
+            "bench" => {
+                let mut days = vec![];
+                let mut iterations = 10;
+                let mut threshold = 10.0;
+                let mut baseline = None;
+                let mut output = PathBuf::from(DEFAULT_BENCH_OUTPUT);
+
+                while let Some(arg) = args.next() {
+                    let mut value = || args.next().ok_or(format!("missing value for {}", arg));
+
+                    match arg.as_str() {
+                        "--iterations" => {
+                            let value = value()?;
+                            iterations = value
+                                .parse::<usize>()
+                                .ok()
+                                .filter(|iterations| *iterations > 0)
+                                .ok_or(format!("invalid iterations '{}'", value))?;
+                        }
+                        "--threshold" => {
+                            let value = value()?;
+                            threshold = value
+                                .parse::<f64>()
+                                .map_err(|_| format!("invalid threshold '{}'", value))?;
+                        }
+                        "--baseline" => baseline = Some(PathBuf::from(value()?)),
+                        "--output" => output = PathBuf::from(value()?),
+                        _ if arg.starts_with("--") => {
+                            return Err(format!("unknown flag '{}'", arg))
+                        }
+                        _ => days.push(parse_day(&arg)?),
+                    }
+                }
+
+                Command::Bench {
+                    days,
+                    iterations,
+                    threshold,
+                    baseline,
+                    output,
+                }
+            }
+            "verify" => {
+                let mut days = vec![];
+                let mut answers = None;
+                let mut record = false;
+
+                while let Some(arg) = args.next() {
+                    match arg.as_str() {
+                        "--answers" => {
+                            answers = Some(PathBuf::from(
+                                args.next().ok_or(format!("missing value for {}", arg))?,
+                            ))
+                        }
+                        "--record" => record = true,
+                        _ if arg.starts_with("--") => {
+                            return Err(format!("unknown flag '{}'", arg))
+                        }
+                        _ => days.push(parse_day(&arg)?),
+                    }
+                }
+
+                Command::Verify {
+                    days,
+                    answers,
+                    record,
+                }
+            }
+            "new-day" => {
+                let day = parse_day(&args.next().ok_or("missing day")?)?;
+                if !(1..=25).contains(&day) {
+                    return Err(format!("invalid day '{}', expected 1 to 25", day));
+                }
+
+                let mut sample = None;
+                let mut answers = [None, None];
+
+                while let Some(flag) = args.next() {
+                    let mut value = || args.next().ok_or(format!("missing value for {}", flag));
+
+                    match flag.as_str() {
+                        "--sample" => sample = Some(PathBuf::from(value()?)),
+                        "--answer1" => answers[0] = Some(value()?),
+                        "--answer2" => answers[1] = Some(value()?),
+                        _ => return Err(format!("unknown flag '{}'", flag)),
+                    }
+                }
+
+                Command::NewDay {
+                    day,
+                    sample,
+                    answers,
+                }
+            }
+            "gen" => {
+                let day = parse_day(&args.next().ok_or("missing day")?)?;
+                let mut size = DEFAULT_GEN_SIZE;
+                let mut seed = 0;
+
+                while let Some(flag) = args.next() {
+                    let value = args.next().ok_or(format!("missing value for {}", flag))?;
+
+                    match flag.as_str() {
+                        "--size" => {
+                            size = value
+                                .parse::<usize>()
+                                .ok()
+                                .filter(|size| *size > 0)
+                                .ok_or(format!("invalid size '{}'", value))?
+                        }
+                        "--seed" => {
+                            seed = value
+                                .parse::<u64>()
+                                .map_err(|_| format!("invalid seed '{}'", value))?
+                        }
+                        _ => return Err(format!("unknown flag '{}'", flag)),
+                    }
+                }
+
+                Command::Gen { day, size, seed }
+            }
+            _ => return Err(format!("unknown command '{}'", command)),
+        };
+
+        match args.next() {
+            Some(extra) => Err(format!("unexpected argument '{}'", extra)),
+            None => Ok(command),
+        }
+    }
+
+    fn execute(self, year: Option<u32>) -> Result<(), String> {
+        match self {
+            Command::List => {
+                select_days(year, &[])?
+                    .into_iter()
+                    .for_each(|(year, day)| println!("{} day{:02}", year, day.day));
+            }
+            Command::Run {
+                day,
+                part,
+                input,
+                render,
+                animate,
+                explain,
+                trace,
+            } => {
+                let (year, day) = find_day(year, day)?;
+                let input = input::load(year.year, day.day, input.as_deref())
+                    .map_err(|err| err.to_string())?;
+
+                let parsed = parse_input(year.year, day, &input)?;
+
+                if let Some(delay) = animate {
+                    let mut terminal =
+                        Terminal::new(io::stdout(), delay, animate::stdin_controls());
+                    if !day.solution.animate_any(parsed.as_ref(), &mut terminal) {
+                        return Err(format!("{} day {:02} has no animation", year.year, day.day));
+                    }
+                }
+
+                let parts = part.map(|part| vec![part]).unwrap_or(Part::ALL.to_vec());
+                let mut unsolved = vec![];
+                let mut print_answer = |part: Part, answer: Result<String, String>| match answer {
+                    _ if !day.solution.has_part(part) => println!("no part {}", part.number()),
+                    Ok(answer) => println!("{}", answer),
+                    Err(err) => unsolved.push(format!("part {}: {}", part.number(), err)),
+                };
+
+                if explain || trace.is_some() {
+                    let mut json = String::new();
+                    let mut explained = 0;
+
+                    for part in parts {
+                        let mut events: Vec<Event> = vec![];
+                        let Some(answer) =
+                            day.solution.explain_any(parsed.as_ref(), part, &mut events)
+                        else {
+                            println!("part {}: nothing to explain", part.number());
+                            print_answer(part, day.solution.solve_any(parsed.as_ref(), part));
+                            continue;
+                        };
+                        explained += 1;
+
+                        events.into_iter().for_each(|mut event| {
+                            if explain {
+                                println!("part {}: {}", part.number(), event);
+                            }
+                            event.fields.insert(0, ("part", part.number().to_string()));
+                            json.push_str(&event.to_json());
+                            json.push('\n');
+                        });
+                        print_answer(part, answer);
+                    }
+
+                    if explained == 0 {
+                        return Err(format!(
+                            "{} day {:02} has no explanation",
+                            year.year, day.day
+                        ));
+                    }
+                    if let Some(path) = trace {
+                        fs::write(&path, json)
+                            .map_err(|err| format!("cannot write {}: {}", path.display(), err))?;
+                        println!("Traced to {}", path.display());
+                    }
+                } else {
+                    parts.into_iter().for_each(|part| {
+                        print_answer(part, day.solution.solve_any(parsed.as_ref(), part))
+                    });
+                }
+
+                if let Some(path) = render {
+                    let canvas = day
+                        .solution
+                        .render_any(parsed.as_ref())
+                        .ok_or(format!("{} day {:02} has no rendering", year.year, day.day))?;
+                    write_image(&path, &canvas)?;
+                    println!("Rendered {}", path.display());
+                }
+
+                if !unsolved.is_empty() {
+                    return Err(format!(
+                        "{} day {:02} has no answer for {}",
+                        year.year,
+                        day.day,
+                        unsolved.join(", ")
+                    ));
+                }
+            }
+            Command::RunAll => {
+                let mut unsolved = 0;
+                let invalid = select_days(year, &[])?
+                    .into_iter()
+                    .filter(|(year, day)| match input::actual(*year, day.day) {
+                        Ok(input) => match parse_input(*year, day, &input) {
+                            Ok(parsed) => {
+                                Part::ALL.iter().for_each(|part| {
+                                    let answer = if !day.solution.has_part(*part) {
+                                        format!("no part {}", part.number())
+                                    } else {
+                                        day.solution
+                                            .solve_any(parsed.as_ref(), *part)
+                                            .unwrap_or_else(|err| {
+                                                unsolved += 1;
+                                                format!("no answer, {}", err)
+                                            })
+                                    };
+                                    println!(
+                                        "{} day {:02} part {}: {}",
+                                        year,
+                                        day.day,
+                                        part.number(),
+                                        answer
+                                    );
+                                });
+                                false
+                            }
+                            Err(err) => {
+                                println!("{}", err);
+                                true
+                            }
+                        },
+                        Err(_) => {
+                            println!("{} day {:02} skipped: input missing", year, day.day);
+                            false
+                        }
+                    })
+                    .count();
+
+                if invalid > 0 {
+                    return Err(format!("{} day(s) have invalid input", invalid));
+                }
+                if unsolved > 0 {
+                    return Err(format!("{} part(s) have no answer", unsolved));
+                }
+            }
+            Command::Bench {
+                days,
+                iterations,
+                threshold,
+                baseline,
+                output,
+            } => {
+                let baseline = match baseline {
+                    Some(path) => Some(read_baseline(&path)?),
+                    None => read_baseline(&output).ok(),
+                };
+
+                let selected = select_days(year, &days)?;
+                if selected.is_empty() {
+                    return Err("no matching days to bench".to_string());
+                }
+
+                let measurements = selected
+                    .into_iter()
+                    .map(|(year, day)| match input::actual(year, day.day) {
+                        Ok(input) => {
+                            bench::bench_day(year, day.day, day.solution, &input, iterations)
+                                .map_err(|err| invalid_input(year, day, &input, err))
+                        }
+                        Err(_) => {
+                            println!("{} day {:02} skipped: input missing", year, day.day);
+                            Ok(vec![])
+                        }
+                    })
+                    .collect::<Result<Vec<_>, _>>()?
+                    .into_iter()
+                    .flatten()
+                    .inspect(|measurement| {
+                        println!(
+                            "{} day {:02} {}: min {:?}, median {:?}, max {:?}",
+                            measurement.year,
+                            measurement.day,
+                            measurement.stage,
+                            measurement.stats.min,
+                            measurement.stats.median,
+                            measurement.stats.max,
+                        );
+                    })
+                    .collect::<Vec<_>>();
+
+                fs::write(&output, bench::to_baseline(&measurements))
+                    .map_err(|err| format!("cannot write {}: {}", output.display(), err))?;
+
+                if let Some(baseline) = baseline {
+                    let regressions = bench::find_regressions(&measurements, &baseline, threshold);
+
+                    regressions.iter().for_each(|regression| {
+                        println!(
+                            "REGRESSION {} day {:02} {}: median {:?} -> {:?} (+{:.1}%)",
+                            regression.year,
+                            regression.day,
+                            regression.stage,
+                            regression.baseline,
+                            regression.current,
+                            regression.percent,
+                        );
+                    });
+
+                    if !regressions.is_empty() {
+                        return Err(format!(
+                            "{} regression(s) above {}%",
+                            regressions.len(),
+                            threshold
+                        ));
+                    }
+                }
+            }
+            Command::Verify {
+                days,
+                answers: path,
+                record,
+            } => {
+                let path = path.unwrap_or_else(answers::default_path);
+                let mut answers = Answers::load(&path)?;
+                let mut failures = vec![];
+                let mut recorded = 0;
+
+                println!("Year Day  Part 1   Part 2");
+                select_days(year, &days)?
+                    .into_iter()
+                    .for_each(|(year, day)| {
+                        let Ok(input) = input::actual(year, day.day) else {
+                            println!("{} {:02}   missing input", year, day.day);
+                            return;
+                        };
+
+                        let parsed = match parse_input(year, day, &input) {
+                            Ok(parsed) => parsed,
+                            Err(err) => {
+                                println!("{} {:02}   invalid input", year, day.day);
+                                failures.push(err);
+                                return;
+                            }
+                        };
+                        let cells = Part::ALL.map(|part| {
+                            if !day.solution.has_part(part) {
+                                return "none";
+                            }
+                            let answer = match day.solution.solve_any(parsed.as_ref(), part) {
+                                Ok(answer) => answer,
+                                Err(err) => {
+                                    failures.push(format!(
+                                        "{} day {:02} part {}: no answer, {}",
+                                        year,
+                                        day.day,
+                                        part.number(),
+                                        err
+                                    ));
+                                    return "ERROR";
+                                }
+                            };
+
+                            match answers.check(year, day.day, part, &input, &answer) {
+                                Verdict::Pass => "pass",
+                                Verdict::Fail { expected } => {
+                                    failures.push(format!(
+                                        "{} day {:02} part {}: expected {}, got {}",
+                                        year,
+                                        day.day,
+                                        part.number(),
+                                        expected,
+                                        answer
+                                    ));
+                                    "FAIL"
+                                }
+                                Verdict::Unknown if record => {
+                                    answers.insert(Entry {
+                                        year,
+                                        day: day.day,
+                                        part,
+                                        checksum: Some(answers::checksum(&input)),
+                                        answer,
+                                    });
+                                    recorded += 1;
+                                    "recorded"
+                                }
+                                Verdict::Unknown => "unknown",
+                            }
+                        });
+
+                        println!("{} {:02}   {:<8} {}", year, day.day, cells[0], cells[1]);
+                    });
+
+                if recorded > 0 {
+                    answers.save(&path)?;
+                    println!("Recorded {} answer(s) in {}", recorded, path.display());
+                }
+
+                failures.iter().for_each(|failure| println!("{}", failure));
+                if !failures.is_empty() {
+                    return Err(format!("{} check(s) failed verification", failures.len()));
+                }
+            }
+            Command::NewDay {
+                day,
+                sample,
+                answers,
+            } => {
+                let year = year.unwrap_or(years::latest().year);
+                let years_dir = Path::new(env!("CARGO_MANIFEST_DIR"))
+                    .join("src")
+                    .join("years");
+                let dir = years_dir.join(format!("y{}", year));
+                let module = dir.join(format!("day{:02}.rs", day));
+                let registry = dir.join("mod.rs");
+
+                if module.exists() {
+                    return Err(format!("{} already exists", module.display()));
+                }
+
+                let sample = sample
+                    .map(|path| {
+                        fs::read_to_string(&path)
+                            .map_err(|err| format!("cannot read {}: {}", path.display(), err))
+                    })
+                    .transpose()?;
+                let source = scaffold::render(
+                    year,
+                    day,
+                    sample.as_deref(),
+                    [answers[0].as_deref(), answers[1].as_deref()],
+                );
+                let read = |path: &Path| {
+                    fs::read_to_string(path)
+                        .map_err(|err| format!("cannot read {}: {}", path.display(), err))
+                };
+                let write = |path: &Path, content: String| {
+                    fs::write(path, content)
+                        .map_err(|err| format!("cannot write {}: {}", path.display(), err))
+                };
+
+                if registry.exists() {
+                    let updated_registry = scaffold::register(&read(&registry)?, day)?;
+
+                    write(&module, source)?;
+                    write(&registry, updated_registry)?;
+                } else {
+                    let years_registry = years_dir.join("mod.rs");
+                    let updated_years = scaffold::register_year(&read(&years_registry)?, year)?;
+
+                    fs::create_dir_all(&dir)
+                        .map_err(|err| format!("cannot create {}: {}", dir.display(), err))?;
+                    write(&module, source)?;
+                    write(&registry, scaffold::render_year(day))?;
+                    write(&years_registry, updated_years)?;
+
+                    println!("Registered {} in {}", year, years_registry.display());
+                }
+
+                println!("Created {}", module.display());
+                println!("Registered day {} in {}", day, registry.display());
+                println!(
+                    "Put the puzzle input in {}",
+                    input::day_path(&input::roots()[0], year, day).display()
+                );
+            }
+            Command::Gen { day, size, seed } => {
+                let (year, day) = find_day(year, day)?;
+                let input = day
+                    .solution
+                    .generate_any(&mut Rng::new(seed), size)
+                    .ok_or(format!("{} day {:02} has no generator", year.year, day.day))?;
+
+                print!("{}", input);
+            }
+        }
+
+        Ok(())
+    }
+}
+
+fn invalid_input(year: u32, day: &Day, input: &str, err: ParseError) -> String {
+    format!(
+        "invalid input for {} day {:02}: {}",
+        year,
+        day.day,
+        err.diagnostic(input)
+    )
+}
+
+fn parse_input(year: u32, day: &Day, input: &str) -> Result<Box<dyn Any>, String> {
+    day.solution
+        .parse_any(input)
+        .map_err(|err| invalid_input(year, day, input, err))
+}
+
+fn write_image(path: &Path, canvas: &Canvas) -> Result<(), String> {
+    let content = match path.extension().and_then(|extension| extension.to_str()) {
+        Some("ppm") => canvas.to_ppm(),
+        _ => canvas.to_svg().into_bytes(),
+    };
+
+    fs::write(path, content).map_err(|err| format!("cannot write {}: {}", path.display(), err))
+}
+
+fn read_baseline(path: &Path) -> Result<Vec<bench::Measurement>, String> {
+    fs::read_to_string(path)
+        .map_err(|err| format!("cannot read {}: {}", path.display(), err))
+        .and_then(|content| bench::parse_baseline(&content))
+}
+
+fn main() -> ExitCode {
+    let cli = match Cli::parse(std::env::args().skip(1)) {
+        Ok(cli) => cli,
+        Err(err) => {
+            eprintln!("error: {}", err);
+            eprintln!();
+            eprintln!("{}", USAGE);
+            return ExitCode::FAILURE;
+        }
+    };
+
+    match cli.execute() {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(err) => {
+            eprintln!("error: {}", err);
+            ExitCode::FAILURE
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parse(args: &[&str]) -> Result<Command, String> {
+        Command::parse(args.iter().map(|arg| arg.to_string()))
+    }
+
+    #[test]
+    fn test_parse_commands() {
+        assert_eq!(parse(&["list"]), Ok(Command::List));
+        assert_eq!(parse(&["run-all"]), Ok(Command::RunAll));
+        assert_eq!(
+            parse(&["run", "5"]),
+            Ok(Command::Run {
+                day: 5,
+                part: None,
+                input: None,
+                render: None,
+                animate: None,
+                explain: false,
+                trace: None,
+            })
+        );
+        assert_eq!(
+            parse(&["run", "12", "--input", "sample.txt", "--part", "2"]),
+            Ok(Command::Run {
+                day: 12,
+                part: Some(Part::Two),
+                input: Some(PathBuf::from("sample.txt")),
+                render: None,
+                animate: None,
+                explain: false,
+                trace: None,
+            })
+        );
+        assert_eq!(
+            parse(&["run", "10", "--render", "out/loop.svg"]),
+            Ok(Command::Run {
+                day: 10,
+                part: None,
+                input: None,
+                render: Some(PathBuf::from("out/loop.svg")),
+                animate: None,
+                explain: false,
+                trace: None,
+            })
+        );
+        assert_eq!(
+            parse(&["run", "14", "--animate"]),
+            Ok(Command::Run {
+                day: 14,
+                part: None,
+                input: None,
+                render: None,
+                animate: Some(Duration::from_millis(100)),
+                explain: false,
+                trace: None,
+            })
+        );
+        assert_eq!(
+            parse(&["run", "16", "--delay", "5", "--animate"]),
+            Ok(Command::Run {
+                day: 16,
+                part: None,
+                input: None,
+                render: None,
+                animate: Some(Duration::from_millis(5)),
+                explain: false,
+                trace: None,
+            })
+        );
+        assert_eq!(
+            parse(&["run", "19", "--explain", "--trace", "day19.jsonl"]),
+            Ok(Command::Run {
+                day: 19,
+                part: None,
+                input: None,
+                render: None,
+                animate: None,
+                explain: true,
+                trace: Some(PathBuf::from("day19.jsonl")),
+            })
+        );
+    }
+
+    #[test]
+    fn test_parse_bench() {
+        assert_eq!(
+            parse(&["bench"]),
+            Ok(Command::Bench {
+                days: vec![],
+                iterations: 10,
+                threshold: 10.0,
+                baseline: None,
+                output: PathBuf::from("bench_output.txt"),
+            })
+        );
+        assert_eq!(
+            parse(&[
+                "bench",
+                "12",
+                "--iterations",
+                "3",
+                "16",
+                "--threshold",
+                "25"
+            ]),
+            Ok(Command::Bench {
+                days: vec![12, 16],
+                iterations: 3,
+                threshold: 25.0,
+                baseline: None,
+                output: PathBuf::from("bench_output.txt"),
+            })
+        );
+    }
+
+    #[test]
+    fn test_parse_verify() {
+        assert_eq!(
+            parse(&["verify"]),
+            Ok(Command::Verify {
+                days: vec![],
+                answers: None,
+                record: false,
+            })
+        );
+        assert_eq!(
+            parse(&["verify", "3", "--record", "--answers", "mine.txt", "7"]),
+            Ok(Command::Verify {
+                days: vec![3, 7],
+                answers: Some(PathBuf::from("mine.txt")),
+                record: true,
+            })
+        );
+    }
+
+    #[test]
+    fn test_parse_new_day() {
+        assert_eq!(
+            parse(&["new-day", "20"]),
+            Ok(Command::NewDay {
+                day: 20,
+                sample: None,
+                answers: [None, None],
+            })
+        );
+        assert_eq!(
+            parse(&["new-day", "22", "--answer2", "7", "--sample", "s.txt"]),
+            Ok(Command::NewDay {
+                day: 22,
+                sample: Some(PathBuf::from("s.txt")),
+                answers: [None, Some("7".to_string())],
+            })
+        );
+    }
+
+    #[test]
+    fn test_parse_gen() {
+        assert_eq!(
+            parse(&["gen", "17"]),
+            Ok(Command::Gen {
+                day: 17,
+                size: 100,
+                seed: 0,
+            })
+        );
+        assert_eq!(
+            parse(&["gen", "5", "--seed", "42", "--size", "5000"]),
+            Ok(Command::Gen {
+                day: 5,
+                size: 5000,
+                seed: 42,
+            })
+        );
+        assert!(parse(&["gen"]).is_err());
+        assert!(parse(&["gen", "5", "--size", "0"]).is_err());
+        assert!(parse(&["gen", "5", "--seed"]).is_err());
+        assert!(parse(&["gen", "5", "--seed", "-1"]).is_err());
+    }
+
+    #[test]
+    fn test_parse_year() {
+        let cli = |args: &[&str]| Cli::parse(args.iter().map(|arg| arg.to_string()));
+
+        assert_eq!(
+            cli(&["list"]),
+            Ok(Cli {
+                year: None,
+                command: Command::List
+            })
+        );
+        assert_eq!(
+            cli(&["--year", "2022", "run", "5"]),
+            cli(&["run", "5", "--year", "2022"])
+        );
+        assert_eq!(
+            cli(&["verify", "--year", "2024", "3"]),
+            Ok(Cli {
+                year: Some(2024),
+                command: Command::Verify {
+                    days: vec![3],
+                    answers: None,
+                    record: false,
+                }
+            })
+        );
+        assert!(cli(&["list", "--year"]).is_err());
+        assert!(cli(&["list", "--year", "23"]).is_err());
+        assert!(cli(&["--year", "2022", "list", "--year", "2023"]).is_err());
+    }
+
+    #[test]
+    fn test_parse_invalid() {
+        assert!(parse(&[]).is_err());
+        assert!(parse(&["fly"]).is_err());
+        assert!(parse(&["run"]).is_err());
+        assert!(parse(&["run", "x"]).is_err());
+        assert!(parse(&["run", "1", "--part", "3"]).is_err());
+        assert!(parse(&["run", "1", "--input"]).is_err());
+        assert!(parse(&["run", "1", "--render", "out.png"]).is_err());
+        assert!(parse(&["run", "1", "--render", "svg"]).is_err());
+        assert!(parse(&["run", "1", "--delay", "10"]).is_err());
+        assert!(parse(&["run", "1", "--animate", "--delay", "fast"]).is_err());
+        assert!(parse(&["run", "1", "--trace"]).is_err());
+        assert!(parse(&["list", "extra"]).is_err());
+        assert!(parse(&["bench", "--iterations", "0"]).is_err());
+        assert!(parse(&["bench", "--fast"]).is_err());
+        assert!(parse(&["verify", "--answers"]).is_err());
+        assert!(parse(&["new-day"]).is_err());
+        assert!(parse(&["new-day", "26"]).is_err());
+        assert!(parse(&["new-day", "20", "--answer3", "1"]).is_err());
+    }
 }
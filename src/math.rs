@@ -0,0 +1,210 @@
+/// The greatest common divisor, with `gcd(0, 0) == 0`.
+pub fn gcd(mut a: u64, mut b: u64) -> u64 {
+    while b != 0 {
+        (a, b) = (b, a % b);
+    }
+    a
+}
+
+/// The least common multiple, which always fits in a `u128`.
+pub fn lcm(a: u64, b: u64) -> u128 {
+    if a == 0 || b == 0 {
+        return 0;
+    }
+    (a / gcd(a, b)) as u128 * b as u128
+}
+
+/// The least common multiple, or `None` if it doesn't fit in a `u64`.
+pub fn checked_lcm(a: u64, b: u64) -> Option<u64> {
+    lcm(a, b).try_into().ok()
+}
+
+/// Returns `(g, x, y)` where `g` is the greatest common divisor of `a` and
+/// `b`, and `a * x + b * y == g`.
+pub fn extended_gcd(a: i128, b: i128) -> (i128, i128, i128) {
+    let (mut old_r, mut r) = (a, b);
+    let (mut old_x, mut x) = (1, 0);
+    let (mut old_y, mut y) = (0, 1);
+
+    while r != 0 {
+        let quotient = old_r / r;
+        (old_r, r) = (r, old_r - quotient * r);
+        (old_x, x) = (x, old_x - quotient * x);
+        (old_y, y) = (y, old_y - quotient * y);
+    }
+
+    if old_r < 0 {
+        (-old_r, -old_x, -old_y)
+    } else {
+        (old_r, old_x, old_y)
+    }
+}
+
+/// The `x` in `0..modulus` with `a * x ≡ 1`, if `a` and `modulus` are
+/// coprime.
+pub fn mod_inverse(a: u64, modulus: u64) -> Option<u64> {
+    let (g, x, _) = extended_gcd(a as i128, modulus as i128);
+    (g == 1).then(|| x.rem_euclid(modulus as i128) as u64)
+}
+
+/// All integers `x` with `x ≡ residue (mod modulus)`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Congruence {
+    pub residue: u128,
+    pub modulus: u128,
+}
+
+impl Congruence {
+    /// The integers satisfying both `self` and `residue (mod modulus)`, or
+    /// `None` if there are none. The moduli don't need to be coprime.
+    fn combine(self, residue: u64, modulus: u64) -> Option<Self> {
+        let modulus = modulus as u128;
+        let residue = residue as u128 % modulus;
+
+        let g = gcd_u128(self.modulus, modulus);
+        let diff = (residue + modulus - self.residue % modulus) % modulus;
+        if !diff.is_multiple_of(g) {
+            return None;
+        }
+
+        // self.residue + self.modulus * t solves both when
+        // (self.modulus / g) * t ≡ diff / g (mod modulus / g).
+        let reduced = modulus / g;
+        let inverse = mod_inverse(((self.modulus / g) % reduced) as u64, reduced as u64)
+            .expect("coprime after dividing by the gcd");
+        let t = (diff / g) * inverse as u128 % reduced;
+
+        let combined = self
+            .modulus
+            .checked_mul(reduced)
+            .expect("combined modulus overflows u128");
+
+        Some(Self {
+            residue: self.residue + self.modulus * t,
+            modulus: combined,
+        })
+    }
+}
+
+fn gcd_u128(mut a: u128, mut b: u128) -> u128 {
+    while b != 0 {
+        (a, b) = (b, a % b);
+    }
+    a
+}
+
+/// Solves the system of `(residue, modulus)` congruences with the Chinese
+/// Remainder Theorem, giving the smallest solution and the period of all
+/// solutions, or `None` if they contradict each other. Moduli must not be
+/// zero, but don't need to be coprime.
+///
+/// Panics if the least common multiple of the moduli overflows a `u128`.
+pub fn crt(congruences: impl IntoIterator<Item = (u64, u64)>) -> Option<Congruence> {
+    congruences.into_iter().try_fold(
+        Congruence {
+            residue: 0,
+            modulus: 1,
+        },
+        |acc, (residue, modulus)| {
+            assert!(modulus != 0, "congruence modulo zero");
+            acc.combine(residue, modulus)
+        },
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_gcd_lcm() {
+        assert_eq!(gcd(12, 18), 6);
+        assert_eq!(gcd(0, 7), 7);
+        assert_eq!(gcd(0, 0), 0);
+        assert_eq!(lcm(4, 6), 12);
+        assert_eq!(lcm(0, 6), 0);
+
+        let big = u64::MAX - 58; // prime
+        assert_eq!(lcm(big, big - 1), big as u128 * (big - 1) as u128);
+        assert_eq!(checked_lcm(big, big - 1), None);
+        assert_eq!(checked_lcm(big, 1), Some(big));
+    }
+
+    #[test]
+    fn test_extended_gcd() {
+        [(240, 46), (46, 240), (-240, 46), (17, 0), (0, 17), (7, 13)]
+            .into_iter()
+            .for_each(|(a, b)| {
+                let (g, x, y) = extended_gcd(a, b);
+                assert_eq!(
+                    g,
+                    gcd(a.unsigned_abs() as u64, b.unsigned_abs() as u64) as i128
+                );
+                assert_eq!(a * x + b * y, g, "{a} {b}");
+            });
+    }
+
+    #[test]
+    fn test_mod_inverse() {
+        assert_eq!(mod_inverse(3, 11), Some(4));
+        assert_eq!(mod_inverse(10, 17), Some(12));
+        assert_eq!(mod_inverse(6, 9), None);
+        assert_eq!(mod_inverse(5, 1), Some(0));
+    }
+
+    #[test]
+    fn test_crt() {
+        assert_eq!(
+            crt([(2, 3), (3, 5), (2, 7)]),
+            Some(Congruence {
+                residue: 23,
+                modulus: 105
+            })
+        );
+
+        // Not coprime, but consistent.
+        assert_eq!(
+            crt([(2, 4), (4, 6)]),
+            Some(Congruence {
+                residue: 10,
+                modulus: 12
+            })
+        );
+        assert_eq!(crt([(1, 4), (2, 6)]), None);
+
+        // Cycle alignment, where every cycle hits its goal at its length.
+        assert_eq!(
+            crt([(0, 2), (0, 3), (0, 4)]),
+            Some(Congruence {
+                residue: 0,
+                modulus: 12
+            })
+        );
+        assert_eq!(
+            crt([]),
+            Some(Congruence {
+                residue: 0,
+                modulus: 1
+            })
+        );
+    }
+
+    #[test]
+    fn test_crt_brute_force() {
+        (1..12).for_each(|m1| {
+            (1..12).for_each(|m2| {
+                (0..m1).for_each(|r1| {
+                    (0..m2).for_each(|r2| {
+                        let expected = (0..m1 * m2).find(|x| x % m1 == r1 && x % m2 == r2);
+                        let solved = crt([(r1, m1), (r2, m2)]);
+
+                        assert_eq!(solved.map(|c| c.residue as u64), expected);
+                        if let Some(solved) = solved {
+                            assert_eq!(solved.modulus, lcm(m1, m2));
+                        }
+                    })
+                })
+            })
+        });
+    }
+}
@@ -0,0 +1,250 @@
+use std::{fmt, str::FromStr};
+
+/// Where and why a puzzle input failed to parse.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError {
+    pub line: usize,
+    pub column: usize,
+    /// The offending text, empty when the line ended too early.
+    pub found: String,
+    pub expected: String,
+}
+
+impl ParseError {
+    /// Reports `found`, which must be a slice of `input`, as not being
+    /// `expected`. Its position in `input` gives the line and column.
+    pub fn at(input: &str, found: &str, expected: impl Into<String>) -> Self {
+        let offset = (found.as_ptr() as usize)
+            .wrapping_sub(input.as_ptr() as usize)
+            .min(input.len());
+        debug_assert!(
+            offset + found.len() <= input.len(),
+            "'{}' is not a slice of the input",
+            found
+        );
+
+        let before = &input[..offset];
+        let line_start = before.rfind('\n').map_or(0, |index| index + 1);
+
+        Self {
+            line: before.matches('\n').count() + 1,
+            column: before[line_start..].chars().count() + 1,
+            found: found.lines().next().unwrap_or_default().to_string(),
+            expected: expected.into(),
+        }
+    }
+
+    /// The error followed by the offending line of `input`, with the
+    /// offending text underlined.
+    pub fn diagnostic(&self, input: &str) -> String {
+        let line = input.lines().nth(self.line - 1).unwrap_or_default();
+        let gutter = self.line.to_string().len();
+
+        format!(
+            "{}\n{:>gutter$} | {}\n{:gutter$} | {}{}",
+            self,
+            self.line,
+            line,
+            "",
+            " ".repeat(self.column - 1),
+            "^".repeat(self.found.chars().count().max(1)),
+        )
+    }
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "line {}, column {}: expected {}, found ",
+            self.line, self.column, self.expected
+        )?;
+
+        if self.found.is_empty() {
+            f.write_str("nothing")
+        } else {
+            write!(f, "'{}'", self.found)
+        }
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+/// The empty slice just past `text`, for reporting that something is missing
+/// at its end.
+pub fn end(text: &str) -> &str {
+    &text[text.len()..]
+}
+
+/// Parses `token`, a slice of `input`, as a number.
+pub fn number<T: FromStr>(input: &str, token: &str) -> Result<T, ParseError> {
+    token
+        .parse()
+        .map_err(|_| ParseError::at(input, token, "a number"))
+}
+
+/// Like [`str::split_once`], but reports a missing `delimiter` at the end of
+/// `text`.
+pub fn split_once<'a>(
+    input: &str,
+    text: &'a str,
+    delimiter: &str,
+) -> Result<(&'a str, &'a str), ParseError> {
+    text.split_once(delimiter)
+        .ok_or_else(|| ParseError::at(input, end(text), format!("{:?}", delimiter)))
+}
+
+/// Collects exactly `N` pieces of `text`, reporting missing pieces as
+/// `expected` and leftover ones as unexpected.
+pub fn exactly<'a, const N: usize>(
+    input: &str,
+    text: &'a str,
+    mut pieces: impl Iterator<Item = &'a str>,
+    expected: &str,
+) -> Result<[&'a str; N], ParseError> {
+    let mut result = [end(text); N];
+
+    for piece in result.iter_mut() {
+        *piece = pieces
+            .next()
+            .ok_or_else(|| ParseError::at(input, end(text), expected))?;
+    }
+
+    match pieces.next() {
+        Some(extra) => Err(ParseError::at(input, extra, "end of line")),
+        None => Ok(result),
+    }
+}
+
+/// Parses the rectangular block of characters `text`, a slice of `input`,
+/// with `tile`. Rows of a different width are reported, so that truncated
+/// inputs are caught here rather than as an out-of-bounds panic later.
+pub fn grid<T>(
+    input: &str,
+    text: &str,
+    expected: &str,
+    tile: impl Fn(char) -> Option<T>,
+) -> Result<Vec<Vec<T>>, ParseError> {
+    let mut width = None;
+
+    text.lines()
+        .map(|line| {
+            let row = line
+                .char_indices()
+                .map(|(index, ch)| {
+                    tile(ch).ok_or_else(|| {
+                        ParseError::at(input, &line[index..index + ch.len_utf8()], expected)
+                    })
+                })
+                .collect::<Result<Vec<_>, _>>()?;
+
+            let width = *width.get_or_insert(row.len());
+            if row.len() < width {
+                Err(ParseError::at(
+                    input,
+                    end(line),
+                    format!("{} more tile(s)", width - row.len()),
+                ))
+            } else if row.len() > width {
+                let (index, _) = line.char_indices().nth(width).unwrap();
+                Err(ParseError::at(input, &line[index..], "end of line"))
+            } else if row.is_empty() {
+                Err(ParseError::at(input, line, expected))
+            } else {
+                Ok(row)
+            }
+        })
+        .collect::<Result<Vec<_>, _>>()
+        .and_then(|rows| {
+            if rows.is_empty() {
+                Err(ParseError::at(input, text, expected))
+            } else {
+                Ok(rows)
+            }
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const INPUT: &str = "Time: 7 15\nDistance: 9 x0";
+
+    #[test]
+    fn test_at() {
+        let error = ParseError::at(INPUT, &INPUT[21..23], "a number");
+
+        assert_eq!(error.line, 2);
+        assert_eq!(error.column, 11);
+        assert_eq!(error.found, "9 ");
+        assert_eq!(
+            error.to_string(),
+            "line 2, column 11: expected a number, found '9 '"
+        );
+    }
+
+    #[test]
+    fn test_diagnostic() {
+        let error = number::<u32>(INPUT, &INPUT[23..]).unwrap_err();
+
+        assert_eq!(
+            error.diagnostic(INPUT),
+            "line 2, column 13: expected a number, found 'x0'\n\
+             2 | Distance: 9 x0\n  \
+             |             ^^"
+        );
+    }
+
+    #[test]
+    fn test_split_once() {
+        let line = &INPUT[11..];
+
+        assert_eq!(split_once(INPUT, line, ": "), Ok(("Distance", "9 x0")));
+
+        let error = split_once(INPUT, line, "@").unwrap_err();
+        assert_eq!((error.line, error.column), (2, 15));
+        assert_eq!(
+            error.to_string(),
+            "line 2, column 15: expected \"@\", found nothing"
+        );
+    }
+
+    #[test]
+    fn test_exactly() {
+        let line = &INPUT[..10];
+
+        assert_eq!(
+            exactly::<3>(INPUT, line, line.split(' '), "3 fields"),
+            Ok(["Time:", "7", "15"])
+        );
+
+        let error = exactly::<4>(INPUT, line, line.split(' '), "4 fields").unwrap_err();
+        assert_eq!((error.column, error.expected.as_str()), (11, "4 fields"));
+
+        let error = exactly::<2>(INPUT, line, line.split(' '), "2 fields").unwrap_err();
+        assert_eq!((error.column, error.found.as_str()), (9, "15"));
+    }
+
+    #[test]
+    fn test_grid() {
+        let digit = |ch: char| ch.to_digit(10);
+
+        assert_eq!(
+            grid("12\n34", "12\n34", "a digit", digit),
+            Ok(vec![vec![1, 2], vec![3, 4]])
+        );
+
+        let error = grid("12\n3x", "12\n3x", "a digit", digit).unwrap_err();
+        assert_eq!((error.line, error.column), (2, 2));
+        assert_eq!(error.found, "x");
+
+        let error = grid("12\n3", "12\n3", "a digit", digit).unwrap_err();
+        assert_eq!((error.line, error.column), (2, 2));
+        assert_eq!(error.expected, "1 more tile(s)");
+
+        let error = grid("12\n345", "12\n345", "a digit", digit).unwrap_err();
+        assert_eq!((error.line, error.column), (2, 3));
+
+        assert!(grid("", "", "a digit", digit).is_err());
+    }
+}
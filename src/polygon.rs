@@ -0,0 +1,176 @@
+use crate::{dir::Dir4, math, vec2::Vec2};
+
+/// Where a point lies relative to a [`Polygon`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Location {
+    Inside,
+    Boundary,
+    Outside,
+}
+
+/// A simple polygon with its corners on integer coordinates. The last
+/// vertex joins back to the first.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Polygon {
+    vertices: Vec<Vec2>,
+}
+
+impl Polygon {
+    pub fn new(vertices: Vec<Vec2>) -> Self {
+        Self { vertices }
+    }
+
+    /// The polygon traced by walking the `moves` from `start`. Returning to
+    /// `start` at the end is optional.
+    pub fn from_moves(start: Vec2, moves: impl IntoIterator<Item = (Dir4, i64)>) -> Self {
+        let mut vertices = moves
+            .into_iter()
+            .fold(vec![start], |mut acc, (dir, steps)| {
+                let pos = *acc.last().unwrap();
+                acc.push(pos.step_by(dir, steps));
+                acc
+            });
+
+        if vertices.len() > 1 && vertices.last() == Some(&start) {
+            vertices.pop();
+        }
+
+        Self { vertices }
+    }
+
+    pub fn vertices(&self) -> &[Vec2] {
+        &self.vertices
+    }
+
+    fn edges(&self) -> impl Iterator<Item = (Vec2, Vec2)> + '_ {
+        self.vertices
+            .iter()
+            .zip(self.vertices.iter().cycle().skip(1))
+            .map(|(a, b)| (*a, *b))
+    }
+
+    /// Twice the signed area, by the shoelace formula. It is positive when
+    /// the vertices turn from the x axis towards the y axis, which is
+    /// clockwise on screen where y points down.
+    pub fn double_signed_area(&self) -> i64 {
+        self.edges().map(|(a, b)| a.cross(b)).sum()
+    }
+
+    /// The area, which is a multiple of a half.
+    pub fn area(&self) -> f64 {
+        self.double_signed_area().abs() as f64 / 2.0
+    }
+
+    /// How many integer points lie on the edges.
+    pub fn boundary_points(&self) -> i64 {
+        self.edges()
+            .map(|(a, b)| math::gcd(a.x.abs_diff(b.x), a.y.abs_diff(b.y)) as i64)
+            .sum()
+    }
+
+    /// How many integer points lie strictly inside, by Pick's theorem:
+    /// `area = interior + boundary / 2 - 1`.
+    pub fn interior_points(&self) -> i64 {
+        (self.double_signed_area().abs() - self.boundary_points() + 2) / 2
+    }
+
+    /// Every integer point covered, edges included.
+    pub fn lattice_points(&self) -> i64 {
+        self.interior_points() + self.boundary_points()
+    }
+
+    /// Where `point` lies, by casting a ray towards increasing x and
+    /// counting the edges it crosses.
+    pub fn locate(&self, point: Vec2) -> Location {
+        let mut inside = false;
+
+        for (a, b) in self.edges() {
+            let cross = (b - a).cross(point - a);
+            if cross == 0
+                && point.x >= a.x.min(b.x)
+                && point.x <= a.x.max(b.x)
+                && point.y >= a.y.min(b.y)
+                && point.y <= a.y.max(b.y)
+            {
+                return Location::Boundary;
+            }
+
+            // Half-open in y, so a ray through a vertex counts it once.
+            if (a.y > point.y) != (b.y > point.y) && (cross > 0) == (b.y > a.y) {
+                inside = !inside;
+            }
+        }
+
+        if inside {
+            Location::Inside
+        } else {
+            Location::Outside
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn polygon(vertices: &[(i64, i64)]) -> Polygon {
+        Polygon::new(vertices.iter().map(|&(x, y)| Vec2::new(x, y)).collect())
+    }
+
+    #[test]
+    fn test_area() {
+        let square = polygon(&[(0, 0), (4, 0), (4, 4), (0, 4)]);
+        assert_eq!(square.double_signed_area(), 32);
+        assert_eq!(square.area(), 16.0);
+        assert_eq!(square.boundary_points(), 16);
+        assert_eq!(square.interior_points(), 9);
+        assert_eq!(square.lattice_points(), 25);
+
+        let reversed = polygon(&[(0, 4), (4, 4), (4, 0), (0, 0)]);
+        assert_eq!(reversed.double_signed_area(), -32);
+        assert_eq!(reversed.interior_points(), 9);
+
+        let triangle = polygon(&[(0, 0), (3, 1), (1, 3)]);
+        assert_eq!(triangle.area(), 4.0);
+        assert_eq!(triangle.boundary_points(), 4);
+        assert_eq!(triangle.interior_points(), 3);
+    }
+
+    #[test]
+    fn test_from_moves() {
+        use Dir4::*;
+
+        let moves = [(Right, 2), (Down, 2), (Left, 2), (Up, 2)];
+        let square = Polygon::from_moves(Vec2::ZERO, moves);
+
+        assert_eq!(square, polygon(&[(0, 0), (2, 0), (2, 2), (0, 2)]));
+        assert_eq!(square.lattice_points(), 9);
+        assert_eq!(Polygon::from_moves(Vec2::ZERO, moves[..3].to_vec()), square);
+    }
+
+    #[test]
+    fn test_locate() {
+        // An L shape, to have a concave corner and collinear vertices.
+        let shape = polygon(&[(0, 0), (2, 0), (4, 0), (4, 2), (2, 2), (2, 4), (0, 4)]);
+        let locate = |x, y| shape.locate(Vec2::new(x, y));
+
+        assert_eq!(locate(1, 1), Location::Inside);
+        assert_eq!(locate(3, 1), Location::Inside);
+        assert_eq!(locate(1, 3), Location::Inside);
+        assert_eq!(locate(3, 3), Location::Outside);
+        assert_eq!(locate(-1, 0), Location::Outside);
+        assert_eq!(locate(5, 2), Location::Outside);
+        assert_eq!(locate(2, 0), Location::Boundary);
+        assert_eq!(locate(2, 3), Location::Boundary);
+        assert_eq!(locate(4, 2), Location::Boundary);
+
+        let count = |location| {
+            (-1..6)
+                .flat_map(|x| (-1..6).map(move |y| (x, y)))
+                .filter(|&(x, y)| locate(x, y) == location)
+                .count() as i64
+        };
+        assert_eq!(count(Location::Inside), shape.interior_points());
+        assert_eq!(count(Location::Boundary), shape.boundary_points());
+    }
+}
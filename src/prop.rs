@@ -0,0 +1,189 @@
+//! Property-based testing: checks a property on many generated inputs, and
+//! shrinks the first input it fails on to a small reproduction.
+
+use std::{env, fmt::Debug, iter};
+
+use crate::rng::Rng;
+
+/// The seed of the first case, unless `PROP_SEED` is set.
+const DEFAULT_SEED: u64 = 2023;
+
+/// How many smaller inputs to try at most once a property failed.
+const MAX_SHRINKS: usize = 1000;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Failure<T> {
+    /// The seed that generates `original`.
+    pub seed: u64,
+    pub original: T,
+    /// The smallest failing input shrinking found.
+    pub shrunk: T,
+    /// Why the property failed on `shrunk`.
+    pub message: String,
+}
+
+/// Checks `property` on `cases` inputs made by `generate`, case `i` with
+/// the seed `seed + i`. On failure, repeatedly replaces the input by the
+/// first of its `shrink` candidates that still fails.
+pub fn find_failure<T: Clone>(
+    seed: u64,
+    cases: u64,
+    generate: impl Fn(&mut Rng) -> T,
+    shrink: impl Fn(&T) -> Vec<T>,
+    property: impl Fn(&T) -> Result<(), String>,
+) -> Option<Failure<T>> {
+    let (seed, original, mut message) = (0..cases).find_map(|case| {
+        let seed = seed.wrapping_add(case);
+        let input = generate(&mut Rng::new(seed));
+        property(&input).err().map(|message| (seed, input, message))
+    })?;
+
+    let mut shrunk = original.clone();
+    let mut tried = 0;
+    while tried < MAX_SHRINKS {
+        let smaller = shrink(&shrunk)
+            .into_iter()
+            .take(MAX_SHRINKS - tried)
+            .find_map(|candidate| {
+                tried += 1;
+                property(&candidate)
+                    .err()
+                    .map(|message| (candidate, message))
+            });
+
+        let Some(smaller) = smaller else { break };
+        (shrunk, message) = smaller;
+    }
+
+    Some(Failure {
+        seed,
+        original,
+        shrunk,
+        message,
+    })
+}
+
+/// [`find_failure`], panicking with the shrunk input if there is one. Set
+/// `PROP_SEED` to start from another seed, e.g. the one a failure reports.
+pub fn check<T: Clone + Debug>(
+    cases: u64,
+    generate: impl Fn(&mut Rng) -> T,
+    shrink: impl Fn(&T) -> Vec<T>,
+    property: impl Fn(&T) -> Result<(), String>,
+) {
+    let seed = env::var("PROP_SEED")
+        .ok()
+        .and_then(|seed| seed.parse().ok())
+        .unwrap_or(DEFAULT_SEED);
+
+    if let Some(failure) = find_failure(seed, cases, generate, shrink, property) {
+        panic!(
+            "property failed: {}\nshrunk input: {:?}\noriginal input: {:?}\n\
+             rerun with PROP_SEED={} to reproduce",
+            failure.message, failure.shrunk, failure.original, failure.seed
+        );
+    }
+}
+
+/// Passes if the fast and the reference solver agree.
+pub fn agree<A: PartialEq + Debug>(fast: A, reference: A) -> Result<(), String> {
+    if fast == reference {
+        Ok(())
+    } else {
+        Err(format!(
+            "got {:?}, the reference says {:?}",
+            fast, reference
+        ))
+    }
+}
+
+/// Smaller numbers to try in place of `x`: zero, then ever closer to `x`,
+/// so that shrinking homes in on a limit in a few steps.
+pub fn shrink_number(x: u64) -> Vec<u64> {
+    let mut smaller = vec![0];
+    smaller.extend(
+        iter::successors(Some(x / 2), |d| Some(d / 2))
+            .take_while(|d| *d > 0)
+            .map(|d| x - d),
+    );
+    smaller.retain(|y| *y < x);
+    smaller.dedup();
+    smaller
+}
+
+/// Smaller lists to try in place of `items`: each with one item left out,
+/// then each with one item shrunk by `shrink_item`.
+pub fn shrink_vec<T: Clone>(items: &[T], shrink_item: impl Fn(&T) -> Vec<T>) -> Vec<Vec<T>> {
+    let removed = (0..items.len()).map(|i| [&items[..i], &items[i + 1..]].concat());
+    let shrunk = items.iter().enumerate().flat_map(|(i, item)| {
+        shrink_item(item).into_iter().map(move |smaller| {
+            let mut items = items.to_vec();
+            items[i] = smaller;
+            items
+        })
+    });
+
+    removed.chain(shrunk).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn numbers(rng: &mut Rng) -> Vec<u64> {
+        (0..rng.range(0..20)).map(|_| rng.range(0..1000)).collect()
+    }
+
+    #[test]
+    fn test_find_failure() {
+        let failure = find_failure(
+            1,
+            100,
+            numbers,
+            |items| shrink_vec(items, |x| shrink_number(*x)),
+            |items| match items.iter().sum::<u64>() {
+                sum if sum < 500 => Ok(()),
+                sum => Err(format!("sum {}", sum)),
+            },
+        )
+        .unwrap();
+
+        assert_eq!(failure.original, numbers(&mut Rng::new(failure.seed)));
+        // Every item counts towards a sum just at the limit.
+        assert_eq!(failure.shrunk.iter().sum::<u64>(), 500);
+        assert!(!failure.shrunk.contains(&0));
+        assert_eq!(failure.message, "sum 500");
+    }
+
+    #[test]
+    fn test_check() {
+        check(
+            50,
+            numbers,
+            |_| vec![],
+            |items| agree(items.iter().rev().sum::<u64>(), items.iter().sum()),
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "shrunk input: [0, 0]")]
+    fn test_check_panics() {
+        check(
+            50,
+            numbers,
+            |items| shrink_vec(items, |x| shrink_number(*x)),
+            |items| agree(items.len() < 2, true),
+        );
+    }
+
+    #[test]
+    fn test_shrink() {
+        assert_eq!(shrink_number(10), [0, 5, 8, 9]);
+        assert_eq!(shrink_number(1), [0]);
+        assert!(shrink_number(0).is_empty());
+        assert_eq!(
+            shrink_vec(&[2, 1], |x| shrink_number(*x)),
+            [vec![1], vec![2], vec![0, 1], vec![1, 1], vec![2, 0]]
+        );
+    }
+}
@@ -0,0 +1,304 @@
+//! A network of modules that pass low and high pulses to each other, as in
+//! 2023 day 20. The network's wiring is fixed, while its [`State`] is a
+//! plain value that can be stored, compared and pressed on from again.
+
+use std::collections::{HashMap, VecDeque};
+
+/// The name of the module that sends the first pulse of every press.
+pub const BUTTON: &str = "button";
+/// The name of the module the button sends its pulse to.
+pub const BROADCASTER: &str = "broadcaster";
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Kind {
+    /// Sends a low pulse to the broadcaster on every press.
+    Button,
+    /// Repeats every pulse to all of its outputs.
+    Broadcaster,
+    /// Ignores high pulses, and flips on every low pulse, sending high when
+    /// it turns on and low when it turns off.
+    FlipFlop,
+    /// Remembers the last pulse from each of its inputs, and sends low if
+    /// they were all high, high otherwise.
+    Conjunction,
+    /// Receives pulses but does nothing with them, like `output` or `rx`.
+    Sink,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Pulse {
+    pub from: usize,
+    pub to: usize,
+    pub high: bool,
+}
+
+#[derive(Debug, Clone)]
+struct Module {
+    name: String,
+    kind: Kind,
+    inputs: Vec<usize>,
+    /// Each output, with the slot of this module among the output's inputs.
+    outputs: Vec<(usize, usize)>,
+}
+
+/// The wiring of the modules, which refer to each other by index.
+#[derive(Debug, Clone)]
+pub struct Network {
+    modules: Vec<Module>,
+    indices: HashMap<String, usize>,
+}
+
+/// What every module remembers between presses.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct State {
+    on: Vec<bool>,
+    /// For each module, the last pulse from each of its inputs.
+    memory: Vec<Vec<bool>>,
+}
+
+impl State {
+    /// Whether the flip-flop `module` is on.
+    pub fn is_on(&self, module: usize) -> bool {
+        self.on[module]
+    }
+
+    /// Whether the last pulse the conjunction `module` received from its
+    /// `slot`th input was high.
+    pub fn remembers_high(&self, module: usize, slot: usize) -> bool {
+        self.memory[module][slot]
+    }
+}
+
+impl Network {
+    /// Wires up `(name, kind, outputs)` modules. Outputs that aren't defined
+    /// become sinks, and a button is wired to the broadcaster.
+    pub fn new<'a>(modules: impl IntoIterator<Item = (&'a str, Kind, Vec<&'a str>)>) -> Self {
+        let mut network = Self {
+            modules: vec![],
+            indices: HashMap::new(),
+        };
+
+        let modules = modules.into_iter().collect::<Vec<_>>();
+        modules.iter().for_each(|(name, kind, _)| {
+            let index = network.add(name);
+            network.modules[index].kind = *kind;
+        });
+
+        let button = network.add(BUTTON);
+        network.modules[button].kind = Kind::Button;
+        let wires = modules
+            .iter()
+            .flat_map(|(name, _, outputs)| outputs.iter().map(move |output| (*name, *output)))
+            .chain([(BUTTON, BROADCASTER)])
+            .collect::<Vec<_>>();
+        wires.into_iter().for_each(|(from, to)| {
+            let (from, to) = (network.add(from), network.add(to));
+            let slot = network.modules[to].inputs.len();
+            network.modules[to].inputs.push(from);
+            network.modules[from].outputs.push((to, slot));
+        });
+
+        network
+    }
+
+    /// The index of `name`, adding it as a sink if it's new.
+    fn add(&mut self, name: &str) -> usize {
+        if let Some(index) = self.indices.get(name) {
+            return *index;
+        }
+
+        self.modules.push(Module {
+            name: name.to_string(),
+            kind: Kind::Sink,
+            inputs: vec![],
+            outputs: vec![],
+        });
+        self.indices
+            .insert(name.to_string(), self.modules.len() - 1);
+        self.modules.len() - 1
+    }
+
+    pub fn len(&self) -> usize {
+        self.modules.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.modules.is_empty()
+    }
+
+    pub fn index(&self, name: &str) -> Option<usize> {
+        self.indices.get(name).copied()
+    }
+
+    pub fn name(&self, module: usize) -> &str {
+        &self.modules[module].name
+    }
+
+    pub fn kind(&self, module: usize) -> Kind {
+        self.modules[module].kind
+    }
+
+    pub fn inputs(&self, module: usize) -> &[usize] {
+        &self.modules[module].inputs
+    }
+
+    pub fn outputs(&self, module: usize) -> impl Iterator<Item = usize> + '_ {
+        self.modules[module]
+            .outputs
+            .iter()
+            .map(|(output, _)| *output)
+    }
+
+    /// Every flip-flop off, and every conjunction remembering low pulses.
+    pub fn initial_state(&self) -> State {
+        State {
+            on: vec![false; self.modules.len()],
+            memory: self
+                .modules
+                .iter()
+                .map(|module| vec![false; module.inputs.len()])
+                .collect(),
+        }
+    }
+
+    /// Presses the button once, passing every pulse to `observe` in the
+    /// order they are delivered, and updating `state` as they arrive.
+    pub fn press(&self, state: &mut State, mut observe: impl FnMut(Pulse)) {
+        let button = self.indices[BUTTON];
+        let mut queue = VecDeque::new();
+        self.send(&mut queue, button, false);
+
+        while let Some((pulse, slot)) = queue.pop_front() {
+            observe(pulse);
+
+            let to = pulse.to;
+            match self.modules[to].kind {
+                Kind::Broadcaster => self.send(&mut queue, to, pulse.high),
+                Kind::FlipFlop if !pulse.high => {
+                    state.on[to] = !state.on[to];
+                    self.send(&mut queue, to, state.on[to]);
+                }
+                Kind::Conjunction => {
+                    state.memory[to][slot] = pulse.high;
+                    let all_high = state.memory[to].iter().all(|high| *high);
+                    self.send(&mut queue, to, !all_high);
+                }
+                Kind::FlipFlop | Kind::Button | Kind::Sink => {}
+            }
+        }
+    }
+
+    fn send(&self, queue: &mut VecDeque<(Pulse, usize)>, from: usize, high: bool) {
+        queue.extend(self.modules[from].outputs.iter().map(|(to, slot)| {
+            (
+                Pulse {
+                    from,
+                    to: *to,
+                    high,
+                },
+                *slot,
+            )
+        }));
+    }
+
+    /// Every pulse of one press from `state`, without changing it.
+    pub fn replay(&self, state: &State) -> Vec<Pulse> {
+        let mut pulses = vec![];
+        self.press(&mut state.clone(), |pulse| pulses.push(pulse));
+        pulses
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// The second example of 2023 day 20.
+    fn example() -> Network {
+        Network::new([
+            ("broadcaster", Kind::Broadcaster, vec!["a"]),
+            ("a", Kind::FlipFlop, vec!["inv", "con"]),
+            ("inv", Kind::Conjunction, vec!["b"]),
+            ("b", Kind::FlipFlop, vec!["con"]),
+            ("con", Kind::Conjunction, vec!["output"]),
+        ])
+    }
+
+    fn describe(network: &Network, pulses: &[Pulse]) -> Vec<String> {
+        pulses
+            .iter()
+            .map(|pulse| {
+                format!(
+                    "{} -{}-> {}",
+                    network.name(pulse.from),
+                    if pulse.high { "high" } else { "low" },
+                    network.name(pulse.to)
+                )
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_wiring() {
+        let network = example();
+        let con = network.index("con").unwrap();
+        let output = network.index("output").unwrap();
+
+        assert_eq!(network.len(), 7);
+        assert_eq!(network.kind(output), Kind::Sink);
+        assert_eq!(network.kind(network.index(BUTTON).unwrap()), Kind::Button);
+        assert_eq!(
+            network.inputs(con),
+            [network.index("a").unwrap(), network.index("b").unwrap()]
+        );
+        assert_eq!(network.outputs(con).collect::<Vec<_>>(), [output]);
+    }
+
+    #[test]
+    fn test_press() {
+        let network = example();
+        let mut state = network.initial_state();
+        let mut pulses = vec![];
+        network.press(&mut state, |pulse| pulses.push(pulse));
+
+        assert_eq!(
+            describe(&network, &pulses),
+            [
+                "button -low-> broadcaster",
+                "broadcaster -low-> a",
+                "a -high-> inv",
+                "a -high-> con",
+                "inv -low-> b",
+                "con -high-> output",
+                "b -high-> con",
+                "con -low-> output",
+            ]
+        );
+        assert!(state.is_on(network.index("a").unwrap()));
+        assert!(state.remembers_high(network.index("con").unwrap(), 1));
+    }
+
+    #[test]
+    fn test_replay() {
+        let network = example();
+        let mut state = network.initial_state();
+        let mut states = vec![state.clone()];
+        (0..4).for_each(|_| {
+            network.press(&mut state, |_| {});
+            states.push(state.clone());
+        });
+
+        // The example comes back to where it started after four presses.
+        assert_eq!(states[4], states[0]);
+        let third = network.replay(&states[2]);
+        assert_eq!(
+            describe(&network, &third)[..3],
+            [
+                "button -low-> broadcaster",
+                "broadcaster -low-> a",
+                "a -high-> inv"
+            ]
+        );
+        assert_eq!(network.replay(&states[2]), third);
+    }
+}
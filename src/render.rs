@@ -0,0 +1,276 @@
+use std::fmt::{self, Write};
+
+use crate::{grid::Grid, vec2::Vec2};
+
+/// Pixels per cell in PPM images, and user units per cell in SVG ones.
+pub const CELL_SIZE: usize = 4;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Color {
+    pub r: u8,
+    pub g: u8,
+    pub b: u8,
+}
+
+impl Color {
+    pub const BLACK: Color = Color::rgb(0, 0, 0);
+    pub const WHITE: Color = Color::rgb(255, 255, 255);
+    pub const RED: Color = Color::rgb(220, 50, 47);
+    pub const GREEN: Color = Color::rgb(133, 153, 0);
+    pub const BLUE: Color = Color::rgb(38, 139, 210);
+    pub const YELLOW: Color = Color::rgb(250, 200, 40);
+
+    pub const fn rgb(r: u8, g: u8, b: u8) -> Self {
+        Self { r, g, b }
+    }
+
+    pub const fn grey(level: u8) -> Self {
+        Self::rgb(level, level, level)
+    }
+}
+
+/// `#rrggbb`, as used by SVG.
+impl fmt::Display for Color {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "#{:02x}{:02x}{:02x}", self.r, self.g, self.b)
+    }
+}
+
+/// A line through the centres of cells.
+#[derive(Debug, Clone)]
+struct Stroke {
+    points: Vec<Vec2>,
+    color: Color,
+    closed: bool,
+}
+
+/// A picture of a puzzle: one colour per cell, with lines drawn on top.
+#[derive(Debug, Clone)]
+pub struct Canvas {
+    cells: Grid<Color>,
+    strokes: Vec<Stroke>,
+}
+
+impl Canvas {
+    pub fn new(width: usize, height: usize, background: Color) -> Self {
+        Self {
+            cells: Grid::new(width, height, background),
+            strokes: vec![],
+        }
+    }
+
+    /// A canvas the size of `grid`, with each cell coloured by `color`.
+    pub fn from_grid<T>(grid: &Grid<T>, color: impl Fn(&T) -> Color) -> Self {
+        Self {
+            cells: grid.map(color),
+            strokes: vec![],
+        }
+    }
+
+    pub fn width(&self) -> usize {
+        self.cells.width()
+    }
+
+    pub fn height(&self) -> usize {
+        self.cells.height()
+    }
+
+    pub fn color(&self, pos: Vec2) -> Option<Color> {
+        self.cells.get(self.cells.checked_pos(pos)?).copied()
+    }
+
+    /// Colours the cell at `pos`, unless it is outside the canvas.
+    pub fn fill(&mut self, pos: Vec2, color: Color) {
+        if let Some(pos) = self.cells.checked_pos(pos) {
+            self.cells[pos] = color;
+        }
+    }
+
+    /// Draws a line through the centres of the cells at `points`, back to
+    /// the first one if `closed`.
+    pub fn stroke(&mut self, points: impl IntoIterator<Item = Vec2>, color: Color, closed: bool) {
+        self.strokes.push(Stroke {
+            points: points.into_iter().collect(),
+            color,
+            closed,
+        });
+    }
+
+    pub fn to_svg(&self) -> String {
+        let (width, height) = (self.width() * CELL_SIZE, self.height() * CELL_SIZE);
+        let mut svg = format!(
+            "<svg xmlns=\"http://www.w3.org/2000/svg\" width=\"{width}\" height=\"{height}\" \
+             viewBox=\"0 0 {width} {height}\" shape-rendering=\"crispEdges\">\n"
+        );
+
+        // One rectangle per run of equal cells in a row keeps real inputs
+        // down to a reasonable size.
+        self.cells.rows().enumerate().for_each(|(y, row)| {
+            row.chunk_by(|a, b| a == b).fold(0, |x, run| {
+                writeln!(
+                    svg,
+                    "<rect x=\"{}\" y=\"{}\" width=\"{}\" height=\"{}\" fill=\"{}\"/>",
+                    x * CELL_SIZE,
+                    y * CELL_SIZE,
+                    run.len() * CELL_SIZE,
+                    CELL_SIZE,
+                    run[0]
+                )
+                .unwrap();
+                x + run.len()
+            });
+        });
+
+        self.strokes.iter().for_each(|stroke| {
+            let points = stroke
+                .points
+                .iter()
+                .map(|point| {
+                    let (x, y) = centre(*point);
+                    format!("{x},{y}")
+                })
+                .collect::<Vec<_>>()
+                .join(" ");
+
+            writeln!(
+                svg,
+                "<{} points=\"{}\" fill=\"none\" stroke=\"{}\" stroke-width=\"{}\" \
+                 stroke-linejoin=\"round\"/>",
+                if stroke.closed { "polygon" } else { "polyline" },
+                points,
+                stroke.color,
+                CELL_SIZE / 2
+            )
+            .unwrap();
+        });
+
+        svg.push_str("</svg>\n");
+        svg
+    }
+
+    /// A binary PPM (P6) image.
+    pub fn to_ppm(&self) -> Vec<u8> {
+        let (width, height) = (self.width() * CELL_SIZE, self.height() * CELL_SIZE);
+        let mut pixels = Grid::new(width, height, Color::BLACK);
+
+        self.cells.iter().for_each(|((x, y), color)| {
+            (0..CELL_SIZE).for_each(|dy| {
+                (0..CELL_SIZE).for_each(|dx| {
+                    pixels[(x * CELL_SIZE + dx, y * CELL_SIZE + dy)] = *color;
+                })
+            })
+        });
+
+        self.strokes.iter().for_each(|stroke| {
+            let closing = match (stroke.points.first(), stroke.points.last()) {
+                (Some(first), Some(last)) if stroke.closed => Some((*last, *first)),
+                _ => None,
+            };
+
+            stroke
+                .points
+                .windows(2)
+                .map(|pair| (pair[0], pair[1]))
+                .chain(closing)
+                .for_each(|(from, to)| {
+                    let (from, to) = (centre(from), centre(to));
+                    let steps = from.0.abs_diff(to.0).max(from.1.abs_diff(to.1)).max(1) as i64;
+
+                    // Every pixel along the segment, thickened to a square
+                    // of half a cell.
+                    (0..=steps).for_each(|step| {
+                        let x = from.0 + (to.0 - from.0) * step / steps;
+                        let y = from.1 + (to.1 - from.1) * step / steps;
+                        (0..CELL_SIZE as i64 / 2).for_each(|dy| {
+                            (0..CELL_SIZE as i64 / 2).for_each(|dx| {
+                                let offset = CELL_SIZE as i64 / 4;
+                                let pos = Vec2::new(x - offset + dx, y - offset + dy);
+                                if let Some(pos) = pixels.checked_pos(pos) {
+                                    pixels[pos] = stroke.color;
+                                }
+                            })
+                        });
+                    });
+                });
+        });
+
+        let mut ppm = format!("P6\n{} {}\n255\n", width, height).into_bytes();
+        pixels
+            .iter()
+            .for_each(|(_, color)| ppm.extend([color.r, color.g, color.b]));
+        ppm
+    }
+}
+
+/// The centre of the cell at `pos`, in image coordinates.
+fn centre(pos: Vec2) -> (i64, i64) {
+    let size = CELL_SIZE as i64;
+    (pos.x * size + size / 2, pos.y * size + size / 2)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn canvas() -> Canvas {
+        let grid = Grid::from_rows(vec![vec![0, 0, 1], vec![1, 1, 1]]);
+        Canvas::from_grid(&grid, |cell| match cell {
+            0 => Color::WHITE,
+            _ => Color::BLUE,
+        })
+    }
+
+    #[test]
+    fn test_to_svg() {
+        let mut canvas = canvas();
+        canvas.fill(Vec2::new(1, 1), Color::RED);
+        canvas.fill(Vec2::new(5, 5), Color::RED);
+        assert_eq!(canvas.color(Vec2::new(1, 1)), Some(Color::RED));
+        assert_eq!(canvas.color(Vec2::new(5, 5)), None);
+        canvas.stroke([Vec2::new(0, 0), Vec2::new(2, 0)], Color::BLACK, false);
+
+        let svg = canvas.to_svg();
+        let rects = svg
+            .lines()
+            .filter(|line| line.starts_with("<rect"))
+            .collect::<Vec<_>>();
+
+        assert!(svg.starts_with("<svg "));
+        assert!(svg.contains("width=\"12\" height=\"8\""));
+        assert_eq!(
+            rects,
+            [
+                "<rect x=\"0\" y=\"0\" width=\"8\" height=\"4\" fill=\"#ffffff\"/>",
+                "<rect x=\"8\" y=\"0\" width=\"4\" height=\"4\" fill=\"#268bd2\"/>",
+                "<rect x=\"0\" y=\"4\" width=\"4\" height=\"4\" fill=\"#268bd2\"/>",
+                "<rect x=\"4\" y=\"4\" width=\"4\" height=\"4\" fill=\"#dc322f\"/>",
+                "<rect x=\"8\" y=\"4\" width=\"4\" height=\"4\" fill=\"#268bd2\"/>",
+            ]
+        );
+        assert!(svg.contains("<polyline points=\"2,2 10,2\""));
+        assert!(svg.ends_with("</svg>\n"));
+    }
+
+    #[test]
+    fn test_to_ppm() {
+        let mut canvas = canvas();
+        canvas.stroke([Vec2::new(0, 1), Vec2::new(2, 1)], Color::RED, true);
+
+        let ppm = canvas.to_ppm();
+        let header = b"P6\n12 8\n255\n";
+        assert_eq!(&ppm[..header.len()], header);
+
+        let pixels = &ppm[header.len()..];
+        assert_eq!(pixels.len(), 12 * 8 * 3);
+
+        let pixel = |x: usize, y: usize| {
+            let index = (y * 12 + x) * 3;
+            Color::rgb(pixels[index], pixels[index + 1], pixels[index + 2])
+        };
+        assert_eq!(pixel(0, 0), Color::WHITE);
+        assert_eq!(pixel(11, 0), Color::BLUE);
+        assert_eq!(pixel(0, 7), Color::BLUE);
+        assert_eq!(pixel(6, 6), Color::RED);
+        assert_eq!(pixel(6, 3), Color::WHITE);
+    }
+}
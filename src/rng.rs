@@ -0,0 +1,99 @@
+use std::ops::Range;
+
+/// A small seeded pseudo-random generator (SplitMix64). Not for anything
+/// that needs real randomness, but the same seed always gives the same
+/// numbers, which is what tests and generated inputs want.
+#[derive(Debug, Clone)]
+pub struct Rng {
+    state: u64,
+}
+
+impl Rng {
+    pub fn new(seed: u64) -> Self {
+        Self { state: seed }
+    }
+
+    pub fn next_u64(&mut self) -> u64 {
+        self.state = self.state.wrapping_add(0x9e37_79b9_7f4a_7c15);
+        let mut z = self.state;
+        z = (z ^ (z >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+        z ^ (z >> 31)
+    }
+
+    /// A number in `range`, which must not be empty.
+    pub fn range(&mut self, range: Range<u64>) -> u64 {
+        assert!(!range.is_empty(), "empty range {:?}", range);
+        let span = range.end - range.start;
+
+        // Rejecting the incomplete last block of `span`s keeps the result
+        // unbiased.
+        let limit = u64::MAX - u64::MAX % span;
+        loop {
+            let x = self.next_u64();
+            if x < limit {
+                return range.start + x % span;
+            }
+        }
+    }
+
+    /// A number below `n`, as an index.
+    pub fn below(&mut self, n: usize) -> usize {
+        self.range(0..n as u64) as usize
+    }
+
+    /// `true` with a probability of `numerator / denominator`.
+    pub fn chance(&mut self, numerator: u64, denominator: u64) -> bool {
+        self.range(0..denominator) < numerator
+    }
+
+    pub fn choose<'a, T>(&mut self, items: &'a [T]) -> &'a T {
+        &items[self.below(items.len())]
+    }
+
+    pub fn shuffle<T>(&mut self, items: &mut [T]) {
+        (1..items.len()).rev().for_each(|i| {
+            let j = self.below(i + 1);
+            items.swap(i, j);
+        });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_seeded() {
+        let numbers = |seed| {
+            let mut rng = Rng::new(seed);
+            (0..5).map(|_| rng.next_u64()).collect::<Vec<_>>()
+        };
+
+        assert_eq!(numbers(7), numbers(7));
+        assert_ne!(numbers(7), numbers(8));
+        // The first output of SplitMix64 seeded with 0.
+        assert_eq!(numbers(0)[0], 0xe220_a839_7b1d_cdaf);
+    }
+
+    #[test]
+    fn test_range() {
+        let mut rng = Rng::new(1);
+        let mut seen = [0; 6];
+        (0..6000).for_each(|_| seen[rng.range(10..16) as usize - 10] += 1);
+
+        assert!(seen.iter().all(|count| (800..1200).contains(count)));
+        assert_eq!(rng.range(3..4), 3);
+    }
+
+    #[test]
+    fn test_shuffle() {
+        let mut rng = Rng::new(2);
+        let mut items = (0..20).collect::<Vec<_>>();
+        rng.shuffle(&mut items);
+
+        assert_ne!(items, (0..20).collect::<Vec<_>>());
+        items.sort();
+        assert_eq!(items, (0..20).collect::<Vec<_>>());
+    }
+}
@@ -0,0 +1,235 @@
+const TEMPLATE: &str = include_str!("years/template.rs");
+
+const SAMPLE_PLACEHOLDER: &str = r#"const SAMPLE_INPUT: &str = r"";"#;
+
+/// Source of a new `dayNN` module, generated from `years/template.rs`. The
+/// sample input and expected sample answers, when given, pre-fill the
+/// sample tests.
+pub fn render(year: u32, day: u32, sample: Option<&str>, answers: [Option<&str>; 2]) -> String {
+    let mut source = TEMPLATE
+        .replace("Day00", &format!("Day{:02}", day))
+        .replace(
+            "assert_actual(0, 0, ",
+            &format!("assert_actual({}, {}, ", year, day),
+        );
+
+    if let Some(sample) = sample {
+        source = source.replace(
+            SAMPLE_PLACEHOLDER,
+            &format!("const SAMPLE_INPUT: &str = {};", raw_string(sample)),
+        );
+    }
+
+    answers
+        .iter()
+        .zip(["p1", "p2"])
+        .filter_map(|(answer, part)| answer.map(|answer| (answer, part)))
+        .fold(source, |source, (answer, part)| {
+            source.replace(
+                &format!("::{}(SAMPLE_INPUT), \"\")", part),
+                &format!("::{}(SAMPLE_INPUT), {:?})", part, answer),
+            )
+        })
+}
+
+/// `text` as a raw string literal on its own lines, like the hand-written
+/// samples, with enough `#`s to hold any quotes inside it.
+fn raw_string(text: &str) -> String {
+    let text = text.trim_matches(['\r', '\n']);
+    let hashes = (0..)
+        .map(|count| "#".repeat(count))
+        .find(|hashes| !text.contains(&format!("\"{}", hashes)))
+        .unwrap();
+
+    format!("r{0}\"\n{1}\n\"{0}", hashes, text)
+}
+
+fn number(line: &str, prefix: &str) -> Option<u32> {
+    line.strip_prefix(prefix)?
+        .split(|ch: char| !ch.is_ascii_digit())
+        .next()?
+        .parse()
+        .ok()
+}
+
+/// Inserts `line` among the lines starting with `prefix`, keeping them
+/// ordered by the number following the prefix. `what` names that number in
+/// errors.
+fn insert_sorted(
+    lines: &mut Vec<String>,
+    prefix: &str,
+    line: String,
+    (what, key): (&str, u32),
+) -> Result<(), String> {
+    let existing = lines
+        .iter()
+        .enumerate()
+        .filter_map(|(index, line)| Some((index, number(line, prefix)?)))
+        .collect::<Vec<_>>();
+
+    let index = match existing.iter().find(|(_, existing)| *existing >= key) {
+        Some((_, existing)) if *existing == key => {
+            return Err(format!("{} {} is already registered", what, key))
+        }
+        Some((index, _)) => *index,
+        None => match existing.last() {
+            Some((index, _)) => index + 1,
+            None => return Err(format!("cannot find '{}' lines to extend", prefix.trim())),
+        },
+    };
+
+    lines.insert(index, line);
+    Ok(())
+}
+
+/// Source of a new `yYYYY` module whose only solution is `day`.
+pub fn render_year(day: u32) -> String {
+    format!(
+        concat!(
+            "pub mod day{0:02};\n",
+            "\n",
+            "use super::Day;\n",
+            "\n",
+            "pub const DAYS: &[Day] = &[\n",
+            "    day!({0}, day{0:02}::Day{0:02}),\n",
+            "    // One day per line, in order, for new-day to insert into.\n",
+            "];\n",
+        ),
+        day
+    )
+}
+
+/// Adds the `pub mod` declaration and the [`ALL`](crate::years::ALL) entry
+/// for `year` to the source of `years/mod.rs`.
+pub fn register_year(registry: &str, year: u32) -> Result<String, String> {
+    let mut lines = registry.lines().map(String::from).collect::<Vec<_>>();
+
+    insert_sorted(
+        &mut lines,
+        "pub mod y",
+        format!("pub mod y{};", year),
+        ("year", year),
+    )?;
+    insert_sorted(
+        &mut lines,
+        "    year!(",
+        format!("    year!({0}, y{0}),", year),
+        ("year", year),
+    )?;
+
+    Ok(lines.join("\n") + "\n")
+}
+
+/// Adds the `pub mod` declaration and the `DAYS` entry for `day` to the
+/// source of a `yYYYY/mod.rs`.
+pub fn register(registry: &str, day: u32) -> Result<String, String> {
+    let mut lines = registry.lines().map(String::from).collect::<Vec<_>>();
+
+    insert_sorted(
+        &mut lines,
+        "pub mod day",
+        format!("pub mod day{:02};", day),
+        ("day", day),
+    )?;
+    insert_sorted(
+        &mut lines,
+        "    day!(",
+        format!("    day!({0}, day{0:02}::Day{0:02}),", day),
+        ("day", day),
+    )?;
+
+    Ok(lines.join("\n") + "\n")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const REGISTRY: &str = r"pub mod day01;
+pub mod day19;
+pub mod day21;
+
+pub const DAYS: &[Day] = &[
+    day!(1, day01::Day01),
+    day!(19, day19::Day19),
+    day!(21, day21::Day21),
+];
+";
+
+    #[test]
+    fn test_template_placeholders() {
+        assert!(TEMPLATE.contains(SAMPLE_PLACEHOLDER));
+        assert!(TEMPLATE.contains("assert_actual(0, 0, Part::One, Day00::p1)"));
+        assert!(TEMPLATE.contains("Day00::p1(SAMPLE_INPUT), \"\")"));
+        assert!(TEMPLATE.contains("Day00::p2(SAMPLE_INPUT), \"\")"));
+    }
+
+    #[test]
+    fn test_render() {
+        let source = render(2022, 7, None, [None, None]);
+
+        assert!(source.contains("pub struct Day07;"));
+        assert!(source.contains("assert_actual(2022, 7, Part::Two, Day07::p2)"));
+        assert!(source.contains(SAMPLE_PLACEHOLDER));
+        assert!(!source.contains("Day00"));
+    }
+
+    #[test]
+    fn test_render_sample() {
+        let source = render(
+            2023,
+            12,
+            Some("???.### 1,1,3\n.??..??...?##. 1,1,3\n"),
+            [Some("21"), None],
+        );
+
+        assert!(source
+            .contains("const SAMPLE_INPUT: &str = r\"\n???.### 1,1,3\n.??..??...?##. 1,1,3\n\";"));
+        assert!(source.contains("assert_eq!(Day12::p1(SAMPLE_INPUT), \"21\");"));
+        assert!(source.contains("assert_eq!(Day12::p2(SAMPLE_INPUT), \"\");"));
+    }
+
+    #[test]
+    fn test_raw_string() {
+        assert_eq!(raw_string("a\nb"), "r\"\na\nb\n\"");
+        assert_eq!(raw_string("say \"hi\""), "r#\"\nsay \"hi\"\n\"#");
+        assert_eq!(raw_string("\"#x\""), "r##\"\n\"#x\"\n\"##");
+    }
+
+    #[test]
+    fn test_register() {
+        let registry = register(REGISTRY, 20).unwrap();
+
+        assert!(registry.contains("pub mod day19;\npub mod day20;\npub mod day21;\n"));
+        assert!(registry.contains(
+            "    day!(19, day19::Day19),\n    day!(20, day20::Day20),\n    day!(21, day21::Day21),\n"
+        ));
+
+        let registry = register(&registry, 25).unwrap();
+        assert!(registry.contains("pub mod day21;\npub mod day25;\n\n"));
+        assert!(registry.contains("    day!(25, day25::Day25),\n];"));
+    }
+
+    #[test]
+    fn test_register_year() {
+        let registry = register(&render_year(3), 1).unwrap();
+        assert!(registry.starts_with("pub mod day01;\npub mod day03;\n\n"));
+        assert!(registry.contains("    day!(1, day01::Day01),\n    day!(3, day03::Day03),\n"));
+        assert!(!registry.contains("rustfmt"));
+
+        let registry = register_year(include_str!("years/mod.rs"), 1999).unwrap();
+        assert!(registry.contains("pub mod y1999;\npub mod y2023;\n"));
+        assert!(registry.contains("    year!(1999, y1999),\n    year!(2023, y2023),\n"));
+        assert!(register_year(&registry, 2023).is_err());
+    }
+
+    #[test]
+    fn test_register_twice() {
+        assert!(register(REGISTRY, 1).is_err());
+        assert!(register("", 1).is_err());
+        assert_eq!(
+            register(REGISTRY, 2).unwrap().lines().nth(1),
+            Some("pub mod day02;")
+        );
+    }
+}
@@ -0,0 +1,171 @@
+use std::{
+    cmp::Reverse,
+    collections::{BinaryHeap, HashMap},
+    hash::Hash,
+    ops::Add,
+};
+
+/// An optimal path found by [`dijkstra`] or [`astar`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Path<S, C> {
+    pub cost: C,
+    /// Every state from the start to the goal, both included.
+    pub states: Vec<S>,
+}
+
+/// The cheapest path from any of `starts` to a state satisfying `is_goal`,
+/// where `neighbours` gives the states reachable from a state with the cost
+/// of each step. Costs must not be negative.
+pub fn dijkstra<S, C, I>(
+    starts: impl IntoIterator<Item = S>,
+    neighbours: impl FnMut(&S) -> I,
+    is_goal: impl FnMut(&S) -> bool,
+) -> Option<Path<S, C>>
+where
+    S: Clone + Eq + Hash,
+    C: Copy + Ord + Default + Add<Output = C>,
+    I: IntoIterator<Item = (S, C)>,
+{
+    astar(starts, neighbours, |_| C::default(), is_goal)
+}
+
+/// Like [`dijkstra`], but explores states in order of their cost plus
+/// `heuristic`, an estimate of the remaining cost to a goal. The path is
+/// optimal as long as the heuristic never overestimates.
+pub fn astar<S, C, I>(
+    starts: impl IntoIterator<Item = S>,
+    mut neighbours: impl FnMut(&S) -> I,
+    mut heuristic: impl FnMut(&S) -> C,
+    mut is_goal: impl FnMut(&S) -> bool,
+) -> Option<Path<S, C>>
+where
+    S: Clone + Eq + Hash,
+    C: Copy + Ord + Default + Add<Output = C>,
+    I: IntoIterator<Item = (S, C)>,
+{
+    // Every state reached so far, with the cheapest known cost and the
+    // index of the state it was reached from.
+    let mut nodes: Vec<(S, C, Option<usize>)> = vec![];
+    let mut indices: HashMap<S, usize> = HashMap::new();
+    let mut queue = BinaryHeap::new();
+
+    starts.into_iter().for_each(|start| {
+        if !indices.contains_key(&start) {
+            indices.insert(start.clone(), nodes.len());
+            queue.push(Reverse((heuristic(&start), C::default(), nodes.len())));
+            nodes.push((start, C::default(), None));
+        }
+    });
+
+    while let Some(Reverse((_, cost, index))) = queue.pop() {
+        if cost > nodes[index].1 {
+            continue;
+        }
+
+        if is_goal(&nodes[index].0) {
+            let mut states = vec![];
+            let mut current = Some(index);
+            while let Some(index) = current {
+                states.push(nodes[index].0.clone());
+                current = nodes[index].2;
+            }
+            states.reverse();
+
+            return Some(Path { cost, states });
+        }
+
+        for (next, step) in neighbours(&nodes[index].0) {
+            let next_cost = cost + step;
+
+            match indices.get(&next) {
+                Some(&known) if nodes[known].1 <= next_cost => {}
+                Some(&known) => {
+                    nodes[known].1 = next_cost;
+                    nodes[known].2 = Some(index);
+                    queue.push(Reverse((next_cost + heuristic(&next), next_cost, known)));
+                }
+                None => {
+                    indices.insert(next.clone(), nodes.len());
+                    queue.push(Reverse((
+                        next_cost + heuristic(&next),
+                        next_cost,
+                        nodes.len(),
+                    )));
+                    nodes.push((next, next_cost, Some(index)));
+                }
+            }
+        }
+    }
+
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Directed edges of a small weighted graph.
+    const EDGES: [(char, char, u32); 7] = [
+        ('a', 'b', 7),
+        ('a', 'c', 9),
+        ('a', 'f', 14),
+        ('b', 'd', 15),
+        ('c', 'd', 11),
+        ('c', 'f', 2),
+        ('f', 'e', 9),
+    ];
+
+    fn edges(from: &char) -> Vec<(char, u32)> {
+        EDGES
+            .iter()
+            .filter(|(start, _, _)| start == from)
+            .map(|(_, end, cost)| (*end, *cost))
+            .collect()
+    }
+
+    #[test]
+    fn test_dijkstra() {
+        assert_eq!(
+            dijkstra(['a'], edges, |state| *state == 'e'),
+            Some(Path {
+                cost: 20,
+                states: vec!['a', 'c', 'f', 'e'],
+            })
+        );
+        assert_eq!(
+            dijkstra(['a', 'c'], edges, |state| *state == 'd'),
+            Some(Path {
+                cost: 11,
+                states: vec!['c', 'd'],
+            })
+        );
+        assert_eq!(
+            dijkstra(['b'], edges, |state| *state == 'b'),
+            Some(Path {
+                cost: 0,
+                states: vec!['b'],
+            })
+        );
+        assert_eq!(dijkstra(['d'], edges, |state| *state == 'a'), None);
+    }
+
+    #[test]
+    fn test_astar() {
+        // Walking a line where every step costs 1, towards 10.
+        let mut explored = 0;
+        let path = astar(
+            [0i32],
+            |state| {
+                explored += 1;
+                [(state - 1, 1u32), (state + 1, 1u32)]
+            },
+            |state| state.abs_diff(10),
+            |state| *state == 10,
+        )
+        .unwrap();
+
+        assert_eq!(path.cost, 10);
+        assert_eq!(path.states, (0..=10).collect::<Vec<_>>());
+        assert_eq!(explored, 10);
+    }
+}
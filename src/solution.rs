@@ -0,0 +1,300 @@
+use std::any::Any;
+
+use crate::{animate::Animator, parse::ParseError, render::Canvas, rng::Rng, trace::Tracer};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Part {
+    One,
+    Two,
+}
+
+impl Part {
+    pub const ALL: [Part; 2] = [Part::One, Part::Two];
+
+    pub fn number(&self) -> u32 {
+        match self {
+            Part::One => 1,
+            Part::Two => 2,
+        }
+    }
+}
+
+/// What a part returns: an answer to print, or, for a `Result`, why this
+/// input has none that the runner can report without panicking.
+pub trait Answer {
+    /// `false` for `()`, the answer of a part the puzzle doesn't have.
+    const EXISTS: bool = true;
+
+    fn to_answer(&self) -> Result<String, String>;
+}
+
+macro_rules! display_answer {
+    ($($ty:ty),*) => {
+        $(impl Answer for $ty {
+            fn to_answer(&self) -> Result<String, String> {
+                Ok(self.to_string())
+            }
+        })*
+    };
+}
+
+display_answer!(u32, u64, u128, usize, i32, i64, i128, String, &str);
+
+impl Answer for () {
+    const EXISTS: bool = false;
+
+    fn to_answer(&self) -> Result<String, String> {
+        Err("the puzzle has no such part".to_string())
+    }
+}
+
+impl<T: Answer> Answer for Result<T, String> {
+    const EXISTS: bool = T::EXISTS;
+
+    fn to_answer(&self) -> Result<String, String> {
+        self.as_ref().map_err(Clone::clone)?.to_answer()
+    }
+}
+
+/// A day's puzzle, split into parsing and the two parts so that the parsed
+/// input can be reused by both parts.
+pub trait Solution {
+    type Parsed: 'static;
+    type Answer1: Answer;
+    type Answer2: Answer;
+
+    fn parse(input: &str) -> Result<Self::Parsed, ParseError>;
+    fn part1(parsed: &Self::Parsed) -> Self::Answer1;
+    fn part2(parsed: &Self::Parsed) -> Self::Answer2;
+
+    /// A picture of the solution, for days where one helps to see what the
+    /// solver does.
+    fn render(_parsed: &Self::Parsed) -> Option<Canvas> {
+        None
+    }
+
+    /// Plays a step-wise simulation of the solution frame by frame. Returns
+    /// `false` for days without one.
+    fn animate(_parsed: &Self::Parsed, _animator: &mut dyn Animator) -> bool {
+        false
+    }
+
+    /// Solves `part` while reporting what the solver does to `tracer`.
+    /// Returns `None` for days without tracing.
+    fn explain(
+        _parsed: &Self::Parsed,
+        _part: Part,
+        _tracer: &mut dyn Tracer,
+    ) -> Option<Result<String, String>> {
+        None
+    }
+
+    /// A random input that both parts can solve, growing with `size`: each
+    /// day says what it counts. Returns `None` for days without a generator.
+    fn generate(_rng: &mut Rng, _size: usize) -> Option<String> {
+        None
+    }
+
+    /// Parses and solves part 1, panicking with a diagnostic on invalid
+    /// input or with the reason there is no answer. Meant for tests.
+    fn p1(input: &str) -> String {
+        answer_or_panic(&Self::part1(&parse_or_panic::<Self>(input)))
+    }
+
+    /// Parses and solves part 2, panicking with a diagnostic on invalid
+    /// input or with the reason there is no answer. Meant for tests.
+    fn p2(input: &str) -> String {
+        answer_or_panic(&Self::part2(&parse_or_panic::<Self>(input)))
+    }
+}
+
+fn parse_or_panic<S: Solution + ?Sized>(input: &str) -> S::Parsed {
+    S::parse(input).unwrap_or_else(|err| panic!("{}", err.diagnostic(input)))
+}
+
+fn answer_or_panic(answer: &impl Answer) -> String {
+    answer.to_answer().unwrap_or_else(|err| panic!("{}", err))
+}
+
+/// Object-safe view of a [`Solution`], so that days with different parsed
+/// and answer types can live in the same table.
+pub trait AnySolution: Sync {
+    fn parse_any(&self, input: &str) -> Result<Box<dyn Any>, ParseError>;
+    fn solve_any(&self, parsed: &dyn Any, part: Part) -> Result<String, String>;
+    fn render_any(&self, parsed: &dyn Any) -> Option<Canvas>;
+    fn animate_any(&self, parsed: &dyn Any, animator: &mut dyn Animator) -> bool;
+    fn explain_any(
+        &self,
+        parsed: &dyn Any,
+        part: Part,
+        tracer: &mut dyn Tracer,
+    ) -> Option<Result<String, String>>;
+    fn generate_any(&self, rng: &mut Rng, size: usize) -> Option<String>;
+
+    /// Whether the puzzle has `part` at all, which the last day of a year
+    /// doesn't for part 2.
+    fn has_part(&self, part: Part) -> bool;
+
+    /// Parses and solves `part`: a parse error, or the answer or why there
+    /// is none.
+    fn solve(&self, input: &str, part: Part) -> Result<Result<String, String>, ParseError> {
+        Ok(self.solve_any(self.parse_any(input)?.as_ref(), part))
+    }
+}
+
+impl<S: Solution + Sync> AnySolution for S {
+    fn parse_any(&self, input: &str) -> Result<Box<dyn Any>, ParseError> {
+        Ok(Box::new(S::parse(input)?))
+    }
+
+    fn solve_any(&self, parsed: &dyn Any, part: Part) -> Result<String, String> {
+        let parsed = downcast::<S>(parsed);
+
+        match part {
+            Part::One => S::part1(parsed).to_answer(),
+            Part::Two => S::part2(parsed).to_answer(),
+        }
+    }
+
+    fn render_any(&self, parsed: &dyn Any) -> Option<Canvas> {
+        S::render(downcast::<S>(parsed))
+    }
+
+    fn animate_any(&self, parsed: &dyn Any, animator: &mut dyn Animator) -> bool {
+        S::animate(downcast::<S>(parsed), animator)
+    }
+
+    fn explain_any(
+        &self,
+        parsed: &dyn Any,
+        part: Part,
+        tracer: &mut dyn Tracer,
+    ) -> Option<Result<String, String>> {
+        S::explain(downcast::<S>(parsed), part, tracer)
+    }
+
+    fn generate_any(&self, rng: &mut Rng, size: usize) -> Option<String> {
+        S::generate(rng, size)
+    }
+
+    fn has_part(&self, part: Part) -> bool {
+        match part {
+            Part::One => S::Answer1::EXISTS,
+            Part::Two => S::Answer2::EXISTS,
+        }
+    }
+}
+
+fn downcast<S: Solution>(parsed: &dyn Any) -> &S::Parsed {
+    parsed
+        .downcast_ref::<S::Parsed>()
+        .expect("parsed input belongs to another solution")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parse;
+
+    struct Sum;
+
+    impl Solution for Sum {
+        type Parsed = Vec<u32>;
+        type Answer1 = u32;
+        type Answer2 = Result<usize, String>;
+
+        fn parse(input: &str) -> Result<Self::Parsed, ParseError> {
+            input
+                .split_whitespace()
+                .map(|x| parse::number(input, x))
+                .collect()
+        }
+
+        fn part1(parsed: &Self::Parsed) -> Self::Answer1 {
+            parsed.iter().sum()
+        }
+
+        fn part2(parsed: &Self::Parsed) -> Self::Answer2 {
+            match parsed.len() {
+                0 => Err("no numbers to count".to_string()),
+                len => Ok(len),
+            }
+        }
+    }
+
+    #[test]
+    fn test_solution_helpers() {
+        assert_eq!(Sum::p1("1 2 3"), "6");
+        assert_eq!(Sum::p2("1 2 3"), "3");
+    }
+
+    #[test]
+    fn test_any_solution() {
+        let solution: &dyn AnySolution = &Sum;
+        let parsed = solution.parse_any("4 5").unwrap();
+        assert_eq!(
+            solution.solve_any(parsed.as_ref(), Part::One),
+            Ok("9".to_string())
+        );
+        assert_eq!(
+            solution.solve_any(parsed.as_ref(), Part::Two),
+            Ok("2".to_string())
+        );
+        assert_eq!(solution.solve("4 5 6", Part::Two), Ok(Ok("3".to_string())));
+        assert_eq!(
+            solution.solve("", Part::Two),
+            Ok(Err("no numbers to count".to_string()))
+        );
+        assert!(solution.render_any(parsed.as_ref()).is_none());
+        assert!(!solution.animate_any(parsed.as_ref(), &mut vec![]));
+        assert!(solution
+            .explain_any(parsed.as_ref(), Part::One, &mut vec![])
+            .is_none());
+        assert!(solution.generate_any(&mut Rng::new(0), 10).is_none());
+        assert!(solution.has_part(Part::One) && solution.has_part(Part::Two));
+    }
+
+    #[test]
+    fn test_missing_part() {
+        struct Last;
+
+        impl Solution for Last {
+            type Parsed = ();
+            type Answer1 = u32;
+            type Answer2 = ();
+
+            fn parse(_input: &str) -> Result<Self::Parsed, ParseError> {
+                Ok(())
+            }
+
+            fn part1(_parsed: &Self::Parsed) -> Self::Answer1 {
+                50
+            }
+
+            fn part2(_parsed: &Self::Parsed) -> Self::Answer2 {}
+        }
+
+        let solution: &dyn AnySolution = &Last;
+        assert!(solution.has_part(Part::One));
+        assert!(!solution.has_part(Part::Two));
+        assert!(solution.solve("", Part::Two).unwrap().is_err());
+    }
+
+    #[test]
+    fn test_parse_error() {
+        let error = Sum.solve("4\n5 x", Part::One).unwrap_err();
+        assert_eq!((error.line, error.column), (2, 3));
+    }
+
+    #[test]
+    #[should_panic(expected = "line 1, column 3: expected a number, found 'x'")]
+    fn test_solution_helpers_panic() {
+        Sum::p1("1 x");
+    }
+
+    #[test]
+    #[should_panic(expected = "no numbers to count")]
+    fn test_solution_helpers_no_answer() {
+        Sum::p2("");
+    }
+}
@@ -0,0 +1,139 @@
+use std::fmt::{self, Write};
+
+/// Something a solver did, with named details.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Event {
+    pub kind: &'static str,
+    pub fields: Vec<(&'static str, String)>,
+}
+
+impl Event {
+    /// One JSON object, with the kind under `event` and every detail as a
+    /// string.
+    pub fn to_json(&self) -> String {
+        let mut json = format!("{{\"event\":{}", quote(self.kind));
+        self.fields.iter().for_each(|(name, value)| {
+            write!(json, ",{}:{}", quote(name), quote(value)).unwrap();
+        });
+        json.push('}');
+        json
+    }
+}
+
+/// `kind name=value name=value`.
+impl fmt::Display for Event {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.kind)?;
+        self.fields
+            .iter()
+            .try_for_each(|(name, value)| write!(f, " {}={}", name, value))
+    }
+}
+
+fn quote(text: &str) -> String {
+    let mut quoted = String::from('"');
+    text.chars().for_each(|c| match c {
+        '"' => quoted.push_str("\\\""),
+        '\\' => quoted.push_str("\\\\"),
+        '\n' => quoted.push_str("\\n"),
+        c if c.is_control() => write!(quoted, "\\u{:04x}", c as u32).unwrap(),
+        c => quoted.push(c),
+    });
+    quoted.push('"');
+    quoted
+}
+
+/// Where solvers send their events. Use the [`event!`] macro to emit them,
+/// which skips building events nobody listens to.
+pub trait Tracer {
+    fn enabled(&self) -> bool;
+    fn event(&mut self, event: Event);
+}
+
+/// Drops every event, for solving without tracing.
+impl Tracer for () {
+    fn enabled(&self) -> bool {
+        false
+    }
+
+    fn event(&mut self, _event: Event) {}
+}
+
+/// Keeps every event in order.
+impl Tracer for Vec<Event> {
+    fn enabled(&self) -> bool {
+        true
+    }
+
+    fn event(&mut self, event: Event) {
+        self.push(event);
+    }
+}
+
+/// `event!(tracer, "kind", name = value, ...)` sends an [`Event`] to the
+/// tracer if it is enabled, with each value formatted by `Display`.
+macro_rules! event {
+    ($tracer:expr, $kind:literal $(, $name:ident = $value:expr)* $(,)?) => {{
+        let tracer: &mut dyn $crate::trace::Tracer = $tracer;
+        if tracer.enabled() {
+            tracer.event($crate::trace::Event {
+                kind: $kind,
+                fields: vec![$((stringify!($name), $value.to_string())),*],
+            });
+        }
+    }};
+}
+
+pub(crate) use event;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_event() {
+        let mut events = vec![];
+        event!(&mut events, "split", level = 2, range = "79-92");
+        event!(&mut events, "done");
+
+        let mut formatted = false;
+        event!(
+            &mut (),
+            "ignored",
+            value = {
+                formatted = true;
+                1
+            }
+        );
+        assert!(!formatted);
+
+        assert_eq!(
+            events,
+            [
+                Event {
+                    kind: "split",
+                    fields: vec![("level", "2".to_string()), ("range", "79-92".to_string())],
+                },
+                Event {
+                    kind: "done",
+                    fields: vec![],
+                },
+            ]
+        );
+        assert_eq!(events[0].to_string(), "split level=2 range=79-92");
+        assert_eq!(events[1].to_string(), "done");
+    }
+
+    #[test]
+    fn test_to_json() {
+        let event = Event {
+            kind: "path",
+            fields: vec![("via", "in -> \"px\"\\\n".to_string())],
+        };
+
+        assert_eq!(
+            event.to_json(),
+            r#"{"event":"path","via":"in -> \"px\"\\\n"}"#
+        );
+    }
+}
@@ -0,0 +1,126 @@
+use std::ops::{Add, AddAssign, Mul, Neg, Sub, SubAssign};
+
+use crate::{dir::Dir4, grid::Pos};
+
+/// A 2D integer vector, with `y` growing downwards like the rows of a grid.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Vec2 {
+    pub x: i64,
+    pub y: i64,
+}
+
+impl Vec2 {
+    pub const ZERO: Vec2 = Vec2::new(0, 0);
+
+    pub const fn new(x: i64, y: i64) -> Self {
+        Self { x, y }
+    }
+
+    /// The neighbouring position in direction `dir`.
+    pub fn step(self, dir: Dir4) -> Self {
+        self + dir.offset()
+    }
+
+    /// The position `count` steps away in direction `dir`.
+    pub fn step_by(self, dir: Dir4, count: i64) -> Self {
+        self + dir.offset() * count
+    }
+
+    pub fn manhattan(self, other: Vec2) -> i64 {
+        (self.x - other.x).abs() + (self.y - other.y).abs()
+    }
+
+    /// The z component of the cross product, twice the signed area of the
+    /// triangle formed with the origin.
+    pub fn cross(self, other: Vec2) -> i64 {
+        self.x * other.y - other.x * self.y
+    }
+}
+
+impl From<Pos> for Vec2 {
+    fn from((x, y): Pos) -> Self {
+        Self::new(x as i64, y as i64)
+    }
+}
+
+impl Add for Vec2 {
+    type Output = Vec2;
+
+    fn add(self, other: Vec2) -> Vec2 {
+        Vec2::new(self.x + other.x, self.y + other.y)
+    }
+}
+
+impl Sub for Vec2 {
+    type Output = Vec2;
+
+    fn sub(self, other: Vec2) -> Vec2 {
+        Vec2::new(self.x - other.x, self.y - other.y)
+    }
+}
+
+impl Mul<i64> for Vec2 {
+    type Output = Vec2;
+
+    fn mul(self, factor: i64) -> Vec2 {
+        Vec2::new(self.x * factor, self.y * factor)
+    }
+}
+
+impl Neg for Vec2 {
+    type Output = Vec2;
+
+    fn neg(self) -> Vec2 {
+        Vec2::new(-self.x, -self.y)
+    }
+}
+
+impl AddAssign for Vec2 {
+    fn add_assign(&mut self, other: Vec2) {
+        *self = *self + other;
+    }
+}
+
+impl SubAssign for Vec2 {
+    fn sub_assign(&mut self, other: Vec2) {
+        *self = *self - other;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_arithmetic() {
+        let mut pos = Vec2::new(1, 2);
+
+        assert_eq!(pos + Vec2::new(3, -4), Vec2::new(4, -2));
+        assert_eq!(pos - Vec2::new(3, -4), Vec2::new(-2, 6));
+        assert_eq!(pos * 3, Vec2::new(3, 6));
+        assert_eq!(-pos, Vec2::new(-1, -2));
+
+        pos += Vec2::new(1, 1);
+        pos -= Vec2::new(0, 5);
+        assert_eq!(pos, Vec2::new(2, -2));
+        assert_eq!(Vec2::from((3, 4)), Vec2::new(3, 4));
+    }
+
+    #[test]
+    fn test_step() {
+        let pos = Vec2::new(5, 5);
+
+        assert_eq!(pos.step(Dir4::Up), Vec2::new(5, 4));
+        assert_eq!(pos.step(Dir4::Right), Vec2::new(6, 5));
+        assert_eq!(pos.step_by(Dir4::Down, 3), Vec2::new(5, 8));
+        assert_eq!(pos.step_by(Dir4::Left, 10), Vec2::new(-5, 5));
+    }
+
+    #[test]
+    fn test_manhattan_cross() {
+        assert_eq!(Vec2::new(1, -2).manhattan(Vec2::new(-3, 4)), 10);
+        assert_eq!(Vec2::ZERO.manhattan(Vec2::ZERO), 0);
+        assert_eq!(Vec2::new(2, 0).cross(Vec2::new(0, 3)), 6);
+        assert_eq!(Vec2::new(0, 3).cross(Vec2::new(2, 0)), -6);
+    }
+}
@@ -0,0 +1,95 @@
+use crate::AnySolution;
+
+pub struct Day {
+    pub day: u32,
+    pub solution: &'static dyn AnySolution,
+}
+
+pub struct Year {
+    pub year: u32,
+    pub days: &'static [Day],
+}
+
+impl Year {
+    pub fn find(&self, day: u32) -> Option<&'static Day> {
+        self.days.iter().find(|entry| entry.day == day)
+    }
+}
+
+macro_rules! day {
+    ($day:literal, $solution:expr) => {
+        crate::years::Day {
+            day: $day,
+            solution: &$solution,
+        }
+    };
+}
+
+macro_rules! year {
+    ($year:literal, $module:ident) => {
+        Year {
+            year: $year,
+            days: $module::DAYS,
+        }
+    };
+}
+
+pub mod y2023;
+
+pub const ALL: &[Year] = &[
+    year!(2023, y2023),
+    // One year per line, in order, for new-day to insert into.
+];
+
+pub fn find(year: u32) -> Option<&'static Year> {
+    ALL.iter().find(|entry| entry.year == year)
+}
+
+/// The most recent year with solutions, used when no year is given.
+pub fn latest() -> &'static Year {
+    ALL.iter().max_by_key(|entry| entry.year).unwrap()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{rng::Rng, Part};
+
+    #[test]
+    fn test_generate() {
+        ALL.iter()
+            .flat_map(|year| year.days.iter().map(move |day| (year.year, day)))
+            .for_each(|(year, day)| {
+                (0..3).for_each(|seed| {
+                    // Days fresh from new-day have no generator yet.
+                    let Some(input) = day.solution.generate_any(&mut Rng::new(seed), 20) else {
+                        return;
+                    };
+                    let parsed = day.solution.parse_any(&input).unwrap_or_else(|err| {
+                        panic!(
+                            "{} day {:02} generated invalid input with seed {}: {}",
+                            year,
+                            day.day,
+                            seed,
+                            err.diagnostic(&input)
+                        )
+                    });
+                    Part::ALL
+                        .iter()
+                        .filter(|part| day.solution.has_part(**part))
+                        .for_each(|part| {
+                            if let Err(err) = day.solution.solve_any(parsed.as_ref(), *part) {
+                                panic!(
+                                    "{} day {:02} part {} has no answer with seed {}: {}",
+                                    year,
+                                    day.day,
+                                    part.number(),
+                                    seed,
+                                    err
+                                );
+                            }
+                        });
+                });
+            });
+    }
+}
@@ -0,0 +1,51 @@
+use crate::{parse::ParseError, Solution};
+
+pub struct Day00;
+
+impl Solution for Day00 {
+    type Parsed = String;
+    type Answer1 = String;
+    type Answer2 = String;
+
+    fn parse(input: &str) -> Result<Self::Parsed, ParseError> {
+        Ok(input.trim().to_string())
+    }
+
+    fn part1(_input: &Self::Parsed) -> Self::Answer1 {
+        "".to_string()
+    }
+
+    fn part2(_input: &Self::Parsed) -> Self::Answer2 {
+        "".to_string()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{answers::assert_actual, Part};
+
+    const SAMPLE_INPUT: &str = r"";
+
+    #[test]
+    fn test_p1_sample() {
+        assert_eq!(Day00::p1(SAMPLE_INPUT), "");
+    }
+
+    #[test]
+    #[ignore = "not yet implemented"]
+    fn test_p1_actual() {
+        assert_actual(0, 0, Part::One, Day00::p1);
+    }
+
+    #[test]
+    fn test_p2_sample() {
+        assert_eq!(Day00::p2(SAMPLE_INPUT), "");
+    }
+
+    #[test]
+    #[ignore = "not yet implemented"]
+    fn test_p2_actual() {
+        assert_actual(0, 0, Part::Two, Day00::p2);
+    }
+}
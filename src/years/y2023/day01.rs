@@ -0,0 +1,172 @@
+use crate::{parse::ParseError, rng::Rng, Solution};
+
+fn match_digit(line: &str, start_idx: usize) -> Option<u32> {
+    const DIGITS_MAP: [(&str, u32); 19] = [
+        ("0", 0),
+        ("1", 1),
+        ("2", 2),
+        ("3", 3),
+        ("4", 4),
+        ("5", 5),
+        ("6", 6),
+        ("7", 7),
+        ("8", 8),
+        ("9", 9),
+        ("one", 1),
+        ("two", 2),
+        ("three", 3),
+        ("four", 4),
+        ("five", 5),
+        ("six", 6),
+        ("seven", 7),
+        ("eight", 8),
+        ("nine", 9),
+    ];
+
+    let line = line.get(start_idx..)?;
+
+    DIGITS_MAP
+        .iter()
+        .find(|(text, _)| line.starts_with(text))
+        .map(|(_, digit)| *digit)
+}
+
+pub struct Day01;
+
+impl Solution for Day01 {
+    type Parsed = Vec<String>;
+    type Answer1 = Result<u32, String>;
+    type Answer2 = u32;
+
+    fn parse(input: &str) -> Result<Self::Parsed, ParseError> {
+        // Part 2 also reads spelled-out digits, so that's all a line needs.
+        input
+            .trim()
+            .lines()
+            .map(
+                |line| match (0..line.len()).any(|i| match_digit(line, i).is_some()) {
+                    true => Ok(line.to_string()),
+                    false => Err(ParseError::at(input, line, "a digit")),
+                },
+            )
+            .collect()
+    }
+
+    fn part1(lines: &Self::Parsed) -> Self::Answer1 {
+        lines
+            .iter()
+            .map(|line| {
+                let mut digits = line.chars().filter_map(|c| c.to_digit(10));
+                let first_digit = digits
+                    .next()
+                    .ok_or_else(|| format!("{:?} only has spelled-out digits", line))?;
+                let last_digit = digits.next_back().unwrap_or(first_digit);
+                Ok(first_digit * 10 + last_digit)
+            })
+            .sum()
+    }
+
+    fn part2(lines: &Self::Parsed) -> Self::Answer2 {
+        lines
+            .iter()
+            .map(|line| {
+                let first_digit = (0..line.len())
+                    .find_map(|start_idx| match_digit(line, start_idx))
+                    .expect("parse checked for a digit");
+
+                let last_digit = (0..line.len())
+                    .rev()
+                    .find_map(|start_idx| match_digit(line, start_idx))
+                    .expect("parse checked for a digit");
+
+                first_digit * 10 + last_digit
+            })
+            .sum::<u32>()
+    }
+
+    /// `size` lines of letters with spelled-out digits, and at least one
+    /// real digit each.
+    fn generate(rng: &mut Rng, size: usize) -> Option<String> {
+        const WORDS: [&str; 9] = [
+            "one", "two", "three", "four", "five", "six", "seven", "eight", "nine",
+        ];
+
+        let lines = (0..size).map(|_| {
+            let mut pieces = (0..rng.range(1..8))
+                .map(|_| match rng.below(3) {
+                    0 => rng.choose(&WORDS).to_string(),
+                    1 => rng.range(1..10).to_string(),
+                    _ => (0..rng.range(1..5))
+                        .map(|_| (b'a' + rng.below(26) as u8) as char)
+                        .collect(),
+                })
+                .collect::<Vec<_>>();
+            let at = rng.below(pieces.len() + 1);
+            pieces.insert(at, rng.range(1..10).to_string());
+            pieces.concat() + "\n"
+        });
+
+        Some(lines.collect())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{answers::assert_actual, Part};
+
+    #[test]
+    fn test_parse() {
+        assert_eq!(
+            Day01::parse("1abc2\nabc\n").unwrap_err().to_string(),
+            "line 2, column 1: expected a digit, found 'abc'"
+        );
+        assert_eq!(
+            Day01::part1(&Day01::parse("1abc2\neightwo").unwrap()),
+            Err("\"eightwo\" only has spelled-out digits".to_string())
+        );
+    }
+
+    #[test]
+    fn test_p1_sample() {
+        assert_eq!(
+            Day01::p1(
+                r"
+1abc2
+pqr3stu8vwx
+a1b2c3d4e5f
+treb7uchet
+"
+            ),
+            "142"
+        );
+    }
+
+    #[test]
+    fn test_p1_actual() {
+        assert_actual(2023, 1, Part::One, Day01::p1);
+    }
+
+    #[test]
+    fn test_p2_sample() {
+        assert_eq!(
+            Day01::p2(
+                r"
+two1nine
+eightwothree
+abcone2threexyz
+xtwone3four
+4nineeightseven2
+zoneight234
+7pqrstsixteen
+"
+            ),
+            "281"
+        );
+    }
+
+    #[test]
+    fn test_p2_actual() {
+        assert_actual(2023, 1, Part::Two, Day01::p2);
+    }
+}
@@ -0,0 +1,143 @@
+use crate::{
+    parse::{self, ParseError},
+    rng::Rng,
+    Solution,
+};
+
+type GameRecord = (u32, u32, u32);
+
+pub struct Game {
+    id: usize,
+    records: Vec<GameRecord>,
+}
+
+fn parse_input(input: &str) -> Result<Vec<Game>, ParseError> {
+    input
+        .trim()
+        .lines()
+        .enumerate()
+        .map(|(id, line)| {
+            let id = id + 1;
+            let (_, records) = parse::split_once(input, line, ":")?;
+
+            let records = records
+                .trim()
+                .split(';')
+                .map(|record| {
+                    record
+                        .trim()
+                        .split(',')
+                        .try_fold((0, 0, 0), |acc, current| {
+                            let (count, color) = parse::split_once(input, current.trim(), " ")?;
+                            let count = parse::number::<u32>(input, count)?;
+
+                            match color {
+                                "red" => Ok((count, acc.1, acc.2)),
+                                "green" => Ok((acc.0, count, acc.2)),
+                                "blue" => Ok((acc.0, acc.1, count)),
+                                _ => Err(ParseError::at(input, color, "red, green or blue")),
+                            }
+                        })
+                })
+                .collect::<Result<Vec<_>, _>>()?;
+
+            Ok(Game { id, records })
+        })
+        .collect()
+}
+
+pub struct Day02;
+
+impl Solution for Day02 {
+    type Parsed = Vec<Game>;
+    type Answer1 = usize;
+    type Answer2 = u32;
+
+    fn parse(input: &str) -> Result<Self::Parsed, ParseError> {
+        parse_input(input)
+    }
+
+    fn part1(games: &Self::Parsed) -> Self::Answer1 {
+        const LIMIT: (u32, u32, u32) = (12, 13, 14);
+
+        games
+            .iter()
+            .filter(|game| {
+                game.records
+                    .iter()
+                    .all(|record| record.0 <= LIMIT.0 && record.1 <= LIMIT.1 && record.2 <= LIMIT.2)
+            })
+            .map(|game| game.id)
+            .sum::<usize>()
+    }
+
+    fn part2(games: &Self::Parsed) -> Self::Answer2 {
+        games
+            .iter()
+            .map(|game| {
+                let max_each = game.records.iter().fold((0, 0, 0), |acc, current| {
+                    (
+                        acc.0.max(current.0),
+                        acc.1.max(current.1),
+                        acc.2.max(current.2),
+                    )
+                });
+                max_each.0 * max_each.1 * max_each.2
+            })
+            .sum::<u32>()
+    }
+
+    /// `size` games of up to 6 draws each.
+    fn generate(rng: &mut Rng, size: usize) -> Option<String> {
+        let games = (1..=size).map(|id| {
+            let draws = (0..rng.range(1..7))
+                .map(|_| {
+                    let mut colors = ["red", "green", "blue"];
+                    rng.shuffle(&mut colors);
+                    colors[..rng.range(1..4) as usize]
+                        .iter()
+                        .map(|color| format!("{} {}", rng.range(1..21), color))
+                        .collect::<Vec<_>>()
+                        .join(", ")
+                })
+                .collect::<Vec<_>>();
+            format!("Game {}: {}\n", id, draws.join("; "))
+        });
+
+        Some(games.collect())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{answers::assert_actual, Part};
+
+    const SAMPLE_INPUT: &str = r"
+Game 1: 3 blue, 4 red; 1 red, 2 green, 6 blue; 2 green
+Game 2: 1 blue, 2 green; 3 green, 4 blue, 1 red; 1 green, 1 blue
+Game 3: 8 green, 6 blue, 20 red; 5 blue, 4 red, 13 green; 5 green, 1 red
+Game 4: 1 green, 3 red, 6 blue; 3 green, 6 red; 3 green, 15 blue, 14 red
+Game 5: 6 red, 1 blue, 3 green; 2 blue, 1 red, 2 green
+";
+
+    #[test]
+    fn test_p1_sample() {
+        assert_eq!(Day02::p1(SAMPLE_INPUT), "8");
+    }
+
+    #[test]
+    fn test_p1_actual() {
+        assert_actual(2023, 2, Part::One, Day02::p1);
+    }
+
+    #[test]
+    fn test_p2_sample() {
+        assert_eq!(Day02::p2(SAMPLE_INPUT), "2286");
+    }
+
+    #[test]
+    fn test_p2_actual() {
+        assert_actual(2023, 2, Part::Two, Day02::p2);
+    }
+}
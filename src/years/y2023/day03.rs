@@ -0,0 +1,146 @@
+use std::collections::{HashMap, HashSet};
+
+use crate::{
+    grid::{Grid, Pos},
+    parse::ParseError,
+    rng::Rng,
+    Solution,
+};
+
+/// Whether `pos` holds a symbol, i.e. neither a digit nor a '.'.
+fn is_symbol(map: &Grid<char>, pos: Pos) -> bool {
+    !map[pos].is_ascii_digit() && map[pos] != '.'
+}
+
+fn solve(map: &Grid<char>) -> (u32, u32) {
+    let mut final_sum = 0;
+    let mut star_graph = HashMap::<Pos, Vec<u32>>::new();
+
+    (0..map.height()).for_each(|y| {
+        let row = map.row(y);
+        let mut x = 0;
+
+        while x < row.len() {
+            if !row[x].is_ascii_digit() {
+                x += 1;
+                continue;
+            }
+
+            let mut value = 0;
+            let mut is_part_number = false;
+            let mut stars = HashSet::new();
+
+            while x < row.len() && row[x].is_ascii_digit() {
+                value *= 10;
+                value += row[x] as u32 - '0' as u32;
+
+                map.neighbours8((x, y)).for_each(|neighbour| {
+                    is_part_number |= is_symbol(map, neighbour);
+                    if map[neighbour] == '*' {
+                        stars.insert(neighbour);
+                    }
+                });
+
+                x += 1;
+            }
+
+            if is_part_number {
+                final_sum += value;
+            }
+            stars.into_iter().for_each(|star| {
+                star_graph.entry(star).or_default().push(value);
+            });
+        }
+    });
+
+    let final_gear_ratios_sum = star_graph
+        .values()
+        .filter(|v| v.len() > 1)
+        .map(|v| v.iter().product::<u32>())
+        .sum::<u32>();
+
+    (final_sum, final_gear_ratios_sum)
+}
+
+pub struct Day03;
+
+impl Solution for Day03 {
+    type Parsed = Grid<char>;
+    type Answer1 = u32;
+    type Answer2 = u32;
+
+    fn parse(input: &str) -> Result<Self::Parsed, ParseError> {
+        Grid::parse(input, input.trim(), "an engine schematic", Some)
+    }
+
+    fn part1(map: &Self::Parsed) -> Self::Answer1 {
+        solve(map).0
+    }
+
+    fn part2(map: &Self::Parsed) -> Self::Answer2 {
+        solve(map).1
+    }
+
+    /// A `size` by `size` schematic of numbers up to 999, with a few
+    /// symbols between them.
+    fn generate(rng: &mut Rng, size: usize) -> Option<String> {
+        const SYMBOLS: [char; 10] = ['*', '*', '*', '#', '+', '$', '/', '@', '%', '='];
+
+        let rows = (0..size).map(|_| {
+            let mut row = String::new();
+            while row.len() < size {
+                match rng.below(12) {
+                    0 => {
+                        row.push_str(&rng.range(1..1000).to_string());
+                        row.push('.');
+                    }
+                    1 => row.push(*rng.choose(&SYMBOLS)),
+                    _ => row.push('.'),
+                }
+            }
+            row.truncate(size);
+            row + "\n"
+        });
+
+        Some(rows.collect())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{answers::assert_actual, Part};
+
+    const SAMPLE_INPUT: &str = r"
+467..114..
+...*......
+..35..633.
+......#...
+617*......
+.....+.58.
+..592.....
+......755.
+...$.*....
+.664.598..
+";
+
+    #[test]
+    fn test_p1_sample() {
+        assert_eq!(Day03::p1(SAMPLE_INPUT), "4361");
+    }
+
+    #[test]
+    fn test_p1_actual() {
+        assert_actual(2023, 3, Part::One, Day03::p1);
+    }
+
+    #[test]
+    fn test_p2_sample() {
+        assert_eq!(Day03::p2(SAMPLE_INPUT), "467835");
+    }
+
+    #[test]
+    fn test_p2_actual() {
+        assert_actual(2023, 3, Part::Two, Day03::p2);
+    }
+}
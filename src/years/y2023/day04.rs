@@ -0,0 +1,133 @@
+use std::collections::HashSet;
+
+use crate::{
+    parse::{self, ParseError},
+    rng::Rng,
+    Solution,
+};
+
+fn get_win_counts(input: &str) -> Result<Vec<usize>, ParseError> {
+    input
+        .trim()
+        .lines()
+        .map(|line| {
+            let (winning_numbers, numbers) = parse::split_once(input, line, "|")?;
+            let winning_numbers = parse::split_once(input, winning_numbers, ":")?
+                .1
+                .split_whitespace()
+                .map(|v| parse::number::<u32>(input, v))
+                .collect::<Result<HashSet<_>, _>>()?;
+            let numbers = numbers
+                .split_whitespace()
+                .map(|v| parse::number::<u32>(input, v))
+                .collect::<Result<HashSet<_>, _>>()?;
+            Ok(numbers.intersection(&winning_numbers).count())
+        })
+        .collect()
+}
+
+pub struct Day04;
+
+impl Solution for Day04 {
+    type Parsed = Vec<usize>;
+    type Answer1 = u32;
+    type Answer2 = u32;
+
+    fn parse(input: &str) -> Result<Self::Parsed, ParseError> {
+        get_win_counts(input)
+    }
+
+    fn part1(win_counts: &Self::Parsed) -> Self::Answer1 {
+        win_counts
+            .iter()
+            .map(|&matches| {
+                if matches == 0 {
+                    0
+                } else {
+                    2u32.pow(matches as u32 - 1)
+                }
+            })
+            .sum::<u32>()
+    }
+
+    fn part2(win_counts: &Self::Parsed) -> Self::Answer2 {
+        let mut cards_count = vec![1u32; win_counts.len()];
+
+        // Copies never go past the end of the table.
+        win_counts.iter().enumerate().for_each(|(i, &v)| {
+            ((i + 1)..(i + 1 + v).min(win_counts.len())).for_each(|j| {
+                cards_count[j] += cards_count[i];
+            });
+        });
+
+        cards_count.into_iter().sum::<u32>()
+    }
+
+    /// `size` cards with 10 winning numbers and 25 numbers each. Most cards
+    /// win nothing, so that the copies of part 2 don't run away, and none
+    /// wins copies past the end of the table.
+    fn generate(rng: &mut Rng, size: usize) -> Option<String> {
+        let cards = (1..=size).map(|id| {
+            let mut numbers = (1..100).collect::<Vec<u32>>();
+            rng.shuffle(&mut numbers);
+
+            let matches = if rng.chance(7, 10) {
+                0
+            } else {
+                rng.range(1..4).min((size - id) as u64) as usize
+            };
+            let (winning, rest) = numbers.split_at(10);
+            let mut have = [&winning[..matches], &rest[..25 - matches]].concat();
+            rng.shuffle(&mut have);
+
+            let format = |numbers: &[u32]| {
+                numbers
+                    .iter()
+                    .map(|number| format!("{:2}", number))
+                    .collect::<Vec<_>>()
+                    .join(" ")
+            };
+            format!("Card {:3}: {} | {}\n", id, format(winning), format(&have))
+        });
+
+        Some(cards.collect())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{answers::assert_actual, Part};
+
+    const SAMPLE_INPUT: &str = r"
+Card 1: 41 48 83 86 17 | 83 86  6 31 17  9 48 53
+Card 2: 13 32 20 16 61 | 61 30 68 82 17 32 24 19
+Card 3:  1 21 53 59 44 | 69 82 63 72 16 21 14  1
+Card 4: 41 92 73 84 69 | 59 84 76 51 58  5 54 83
+Card 5: 87 83 26 28 32 | 88 30 70 12 93 22 82 36
+Card 6: 31 18 13 56 72 | 74 77 10 23 35 67 36 11
+";
+
+    #[test]
+    fn test_p1_sample() {
+        assert_eq!(Day04::p1(SAMPLE_INPUT), "13");
+    }
+
+    #[test]
+    fn test_p1_actual() {
+        assert_actual(2023, 4, Part::One, Day04::p1);
+    }
+
+    #[test]
+    fn test_p2_sample() {
+        assert_eq!(Day04::p2(SAMPLE_INPUT), "30");
+
+        // The last card wins copies of cards past the end.
+        assert_eq!(Day04::p2("Card 1: 1 2 | 1 3\nCard 2: 4 5 | 4 5"), "3");
+    }
+
+    #[test]
+    fn test_p2_actual() {
+        assert_actual(2023, 4, Part::Two, Day04::p2);
+    }
+}
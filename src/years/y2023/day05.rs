@@ -0,0 +1,397 @@
+use std::ops::Range;
+
+use crate::{
+    interval::IntervalSet,
+    parse::{self, ParseError},
+    rng::Rng,
+    trace::{event, Tracer},
+    Answer, Part, Solution,
+};
+
+/// Moves the numbers in `src` to the range of the same length starting at
+/// `dst_start`.
+#[derive(Debug)]
+struct Mapper {
+    dst_start: u64,
+    src: Range<u64>,
+}
+
+impl Mapper {
+    fn from_line(input: &str, line: &str) -> Result<Self, ParseError> {
+        let [dst_range_start, src_range_start, range_len] =
+            parse::exactly(input, line, line.split_whitespace(), "3 numbers")?;
+
+        let dst_start = parse::number::<u64>(input, dst_range_start)?;
+        let src_start = parse::number::<u64>(input, src_range_start)?;
+        let len = parse::number::<u64>(input, range_len)?;
+
+        let fits = |start: u64| start.checked_add(len).is_some();
+        if !fits(src_start) || !fits(dst_start) {
+            return Err(ParseError::at(
+                input,
+                range_len,
+                "a range length that stays below 2^64",
+            ));
+        }
+
+        Ok(Self {
+            dst_start,
+            src: src_start..src_start + len,
+        })
+    }
+
+    fn dst(&self) -> Range<u64> {
+        self.shift(&self.src)
+    }
+
+    fn shift(&self, range: &Range<u64>) -> Range<u64> {
+        range.start - self.src.start + self.dst_start..range.end - self.src.start + self.dst_start
+    }
+}
+
+/// Sends every number of `numbers` through one map: numbers are moved by
+/// the first mapper covering them, and keep their value if there is none.
+fn convert(numbers: &IntervalSet, mappers: &[Mapper], tracer: &mut dyn Tracer) -> IntervalSet {
+    let (moved, unmapped) = mappers.iter().fold(
+        (IntervalSet::new(), numbers.clone()),
+        |(moved, unmapped), mapper| {
+            let src = IntervalSet::from(mapper.src.clone());
+            let matched = unmapped.intersection(&src);
+            let shifted = matched
+                .ranges()
+                .iter()
+                .map(|range| mapper.shift(range))
+                .collect::<IntervalSet>();
+
+            if !matched.is_empty() {
+                event!(
+                    tracer,
+                    "split",
+                    src = IntervalSet::from(mapper.src.clone()),
+                    dst = IntervalSet::from(mapper.dst()),
+                    moved = matched,
+                    to = shifted,
+                );
+            }
+
+            (moved.union(&shifted), unmapped.difference(&src))
+        },
+    );
+
+    if !unmapped.is_empty() {
+        event!(tracer, "unmapped", kept = unmapped);
+    }
+
+    moved.union(&unmapped)
+}
+
+#[derive(Debug)]
+pub struct Input {
+    /// At least one pair of numbers. Each number as a seed of part 1, and
+    /// each pair as a start and length of part 2, make a range below 2^64.
+    seeds: Vec<u64>,
+    maps: Vec<Vec<Mapper>>,
+}
+
+impl Input {
+    fn parse_input(input: &str) -> Result<Self, ParseError> {
+        let mut blocks = input.trim().split("\n\n");
+
+        let line = blocks.next().unwrap_or_default();
+        let tokens = parse::split_once(input, line, ":")?
+            .1
+            .split_whitespace()
+            .collect::<Vec<_>>();
+        if tokens.is_empty() || tokens.len() % 2 == 1 {
+            let expected = match tokens.is_empty() {
+                true => "a seed",
+                false => "a range length for the last seed",
+            };
+            return Err(ParseError::at(input, parse::end(line), expected));
+        }
+
+        let seeds = tokens
+            .iter()
+            .map(|x| parse::number::<u64>(input, x))
+            .collect::<Result<Vec<_>, _>>()?;
+        // Part 1 reads every number as a range of one seed, part 2 every
+        // pair, blaming the seed and the length respectively.
+        let ranges = seeds
+            .iter()
+            .map(|seed| (*seed, 1))
+            .chain(seeds.chunks(2).map(|pair| (pair[0], pair[1])));
+        let tokens = tokens.iter().chain(tokens.iter().skip(1).step_by(2));
+        if let Some((token, _)) = tokens
+            .zip(ranges)
+            .find(|(_, (start, len))| *len == 0 || start.checked_add(*len).is_none())
+        {
+            return Err(ParseError::at(
+                input,
+                token,
+                "a non-empty range that stays below 2^64",
+            ));
+        }
+
+        let maps = blocks
+            .map(|description| {
+                description
+                    .lines()
+                    .skip(1)
+                    .map(|line| Mapper::from_line(input, line))
+                    .collect()
+            })
+            .collect::<Result<_, _>>()?;
+
+        Ok(Self { seeds, maps })
+    }
+
+    fn lowest_location(&self, seeds: IntervalSet, tracer: &mut dyn Tracer) -> u64 {
+        self.maps
+            .iter()
+            .enumerate()
+            .fold(seeds, |numbers, (level, mappers)| {
+                event!(tracer, "map", level = level + 1, numbers = numbers);
+                convert(&numbers, mappers, tracer)
+            })
+            .min()
+            .expect("parse checked for a non-empty range of seeds")
+    }
+
+    fn seeds(&self, part: Part) -> IntervalSet {
+        match part {
+            Part::One => self.seeds.iter().map(|&seed| seed..seed + 1).collect(),
+            Part::Two => self
+                .seeds
+                .chunks(2)
+                .map(|seeds| seeds[0]..seeds[0] + seeds[1])
+                .collect(),
+        }
+    }
+}
+
+pub struct Day05;
+
+impl Solution for Day05 {
+    type Parsed = Input;
+    type Answer1 = u64;
+    type Answer2 = u64;
+
+    fn parse(input: &str) -> Result<Self::Parsed, ParseError> {
+        Input::parse_input(input)
+    }
+
+    fn part1(input: &Self::Parsed) -> Self::Answer1 {
+        input.lowest_location(input.seeds(Part::One), &mut ())
+    }
+
+    fn part2(input: &Self::Parsed) -> Self::Answer2 {
+        input.lowest_location(input.seeds(Part::Two), &mut ())
+    }
+
+    /// Shows the numbers reaching each map, and which mapper moves which of
+    /// them where.
+    fn explain(
+        input: &Self::Parsed,
+        part: Part,
+        tracer: &mut dyn Tracer,
+    ) -> Option<Result<String, String>> {
+        Some(input.lowest_location(input.seeds(part), tracer).to_answer())
+    }
+
+    /// An almanac with 10 seed ranges and 7 maps of about `size` mappers
+    /// each. Each map cuts the numbers below 4 billion into pieces and
+    /// shuffles most of them around.
+    fn generate(rng: &mut Rng, size: usize) -> Option<String> {
+        const LIMIT: u64 = 4_000_000_000;
+        const NAMES: [&str; 8] = [
+            "seed",
+            "soil",
+            "fertilizer",
+            "water",
+            "light",
+            "temperature",
+            "humidity",
+            "location",
+        ];
+
+        let seeds = (0..10)
+            .map(|_| {
+                let start = rng.range(0..LIMIT);
+                format!(
+                    "{} {}",
+                    start,
+                    rng.range(1..(LIMIT - start).min(LIMIT / 20) + 1)
+                )
+            })
+            .collect::<Vec<_>>();
+        let mut almanac = format!("seeds: {}\n", seeds.join(" "));
+
+        NAMES.windows(2).for_each(|names| {
+            let mut cuts = (1..size).map(|_| rng.range(1..LIMIT)).collect::<Vec<_>>();
+            cuts.extend([0, LIMIT]);
+            cuts.sort();
+            cuts.dedup();
+
+            // Laying the pieces out again in another order.
+            let mut pieces = cuts
+                .windows(2)
+                .map(|cut| cut[0]..cut[1])
+                .collect::<Vec<_>>();
+            rng.shuffle(&mut pieces);
+            let mut dst_start = 0;
+            let mut mappers = pieces
+                .into_iter()
+                .map(|src| {
+                    let mapper = (dst_start, src.start, src.end - src.start);
+                    dst_start += src.end - src.start;
+                    mapper
+                })
+                .collect::<Vec<_>>();
+
+            // Leaving a few pieces out keeps the unmapped numbers as they
+            // are.
+            mappers.retain(|_| !rng.chance(1, 10));
+            rng.shuffle(&mut mappers);
+
+            almanac.push_str(&format!("\n{}-to-{} map:\n", names[0], names[1]));
+            mappers.into_iter().for_each(|(dst, src, len)| {
+                almanac.push_str(&format!("{} {} {}\n", dst, src, len));
+            });
+        });
+
+        Some(almanac)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::answers::assert_actual;
+
+    const SAMPLE_INPUT: &str = r"
+seeds: 79 14 55 13
+
+seed-to-soil map:
+50 98 2
+52 50 48
+
+soil-to-fertilizer map:
+0 15 37
+37 52 2
+39 0 15
+
+fertilizer-to-water map:
+49 53 8
+0 11 42
+42 0 7
+57 7 4
+
+water-to-light map:
+88 18 7
+18 25 70
+
+light-to-temperature map:
+45 77 23
+81 45 19
+68 64 13
+
+temperature-to-humidity map:
+0 69 1
+1 0 69
+
+humidity-to-location map:
+60 56 37
+56 93 4
+";
+
+    #[test]
+    fn test_p1_sample() {
+        assert_eq!(Day05::p1(SAMPLE_INPUT), "35");
+    }
+
+    #[test]
+    fn test_p1_actual() {
+        assert_actual(2023, 5, Part::One, Day05::p1);
+    }
+
+    #[test]
+    fn test_parse_error() {
+        let error = Day05::parse("seeds: 79 14\n\nseed-to-soil map:\n50 98 2\n52 5x 48")
+            .err()
+            .unwrap();
+        assert_eq!((error.line, error.column), (5, 4));
+        assert_eq!(error.found, "5x");
+
+        let error = Day05::parse("seeds: 79 14\n\nseed-to-soil map:\n50 98")
+            .err()
+            .unwrap();
+        assert_eq!((error.line, error.column), (4, 6));
+        assert_eq!(error.expected, "3 numbers");
+
+        let error = |input| Day05::parse(input).unwrap_err().to_string();
+        assert_eq!(
+            error("seeds:\n\nseed-to-soil map:\n50 98 2"),
+            "line 1, column 7: expected a seed, found nothing"
+        );
+        assert_eq!(
+            error("seeds: 79 14 55\n\nseed-to-soil map:\n50 98 2"),
+            "line 1, column 16: expected a range length for the last seed, found nothing"
+        );
+        assert_eq!(
+            error("seeds: 79 0\n\nseed-to-soil map:\n50 98 2"),
+            "line 1, column 11: expected a non-empty range that stays below 2^64, found '0'"
+        );
+        assert_eq!(
+            error("seeds: 18446744073709551615 1"),
+            "line 1, column 8: expected a non-empty range that stays below 2^64, \
+             found '18446744073709551615'"
+        );
+        assert_eq!(
+            error("seeds: 79 14\n\nseed-to-soil map:\n50 18446744073709551615 2"),
+            "line 4, column 25: expected a range length that stays below 2^64, found '2'"
+        );
+    }
+
+    #[test]
+    fn test_convert() {
+        let input = Day05::parse(SAMPLE_INPUT).unwrap();
+        let seeds = IntervalSet::from_iter([79..93, 55..68]);
+
+        assert_eq!(
+            convert(&seeds, &input.maps[0], &mut ()).ranges(),
+            [57..70, 81..95]
+        );
+        assert_eq!(
+            convert(&IntervalSet::from(45..55), &input.maps[0], &mut ()).ranges(),
+            [45..50, 52..57]
+        );
+    }
+
+    #[test]
+    fn test_explain() {
+        let input = Day05::parse(SAMPLE_INPUT).unwrap();
+        let mut events = vec![];
+        let answer = Day05::explain(&input, Part::Two, &mut events);
+
+        assert_eq!(answer, Some(Ok("46".to_string())));
+        assert_eq!(events[0].to_string(), "map level=1 numbers=55-67, 79-92");
+        assert_eq!(
+            events[1].to_string(),
+            "split src=50-97 dst=52-99 moved=55-67, 79-92 to=57-69, 81-94"
+        );
+        assert_eq!(
+            events.iter().filter(|event| event.kind == "map").count(),
+            input.maps.len()
+        );
+    }
+
+    #[test]
+    fn test_p2_sample() {
+        assert_eq!(Day05::p2(SAMPLE_INPUT), "46");
+    }
+
+    #[test]
+    fn test_p2_actual() {
+        assert_actual(2023, 5, Part::Two, Day05::p2);
+    }
+}
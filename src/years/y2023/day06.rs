@@ -0,0 +1,219 @@
+use crate::{
+    parse::{self, ParseError},
+    rng::Rng,
+    Solution,
+};
+
+/// How many ways there are to hold the button and beat `distance`, by
+/// trying every hold time. Distances pass `u64::MAX` for times above 2^33,
+/// so they are compared as `u128`, as in [`ways_to_win`].
+fn count_wins(time: u64, distance: u64) -> u64 {
+    (0..=time)
+        .filter(|hold| *hold as u128 * (time - hold) as u128 > distance as u128)
+        .count() as u64
+}
+
+/// [`count_wins`] in closed form. The winning hold times lie strictly
+/// between the roots of `hold * (time - hold) = distance`, symmetric around
+/// `time / 2`; the floating point estimate of the lower root is corrected
+/// with exact arithmetic so that integer roots don't count.
+fn ways_to_win(time: u64, distance: u64) -> u64 {
+    let wins = |hold: u64| hold as u128 * (time - hold) as u128 > distance as u128;
+    let discriminant = (time as f64).powi(2) - 4.0 * distance as f64;
+    if discriminant < 0.0 {
+        return 0;
+    }
+
+    let mut lowest = ((time as f64 - discriminant.sqrt()) / 2.0).max(0.0) as u64;
+    lowest = lowest.min(time / 2);
+    while lowest > 0 && wins(lowest - 1) {
+        lowest -= 1;
+    }
+    while lowest <= time / 2 && !wins(lowest) {
+        lowest += 1;
+    }
+
+    if lowest > time / 2 {
+        0
+    } else {
+        time - 2 * lowest + 1
+    }
+}
+
+/// The numbers on a line, and all of them read as one, ignoring the spaces
+/// between them, as part 2 does.
+fn parse_numbers(input: &str, line: &str) -> Result<(Vec<u64>, u64), ParseError> {
+    let numbers = parse::split_once(input, line, ":")?.1;
+    let separate = numbers
+        .split_whitespace()
+        .map(|number| parse::number(input, number))
+        .collect::<Result<Vec<_>, _>>()?;
+    if separate.is_empty() {
+        return Err(ParseError::at(input, parse::end(line), "a number"));
+    }
+
+    let joined = numbers
+        .split_whitespace()
+        .collect::<String>()
+        .parse()
+        .map_err(|_| ParseError::at(input, numbers.trim(), "numbers that fit in a u64 as one"))?;
+
+    Ok((separate, joined))
+}
+
+pub struct Day06;
+
+impl Solution for Day06 {
+    /// Each race's time and record distance, and the one race they make
+    /// when read without the spaces.
+    type Parsed = (Vec<(u64, u64)>, (u64, u64));
+    type Answer1 = u64;
+    type Answer2 = u64;
+
+    fn parse(input: &str) -> Result<Self::Parsed, ParseError> {
+        let lines = input.trim();
+        let [time_line, distance_line] =
+            parse::exactly(input, lines, lines.lines(), "time and distance lines")?;
+
+        let (times, time) = parse_numbers(input, time_line)?;
+        let (distances, distance) = parse_numbers(input, distance_line)?;
+        if distances.len() != times.len() {
+            let numbers = parse::split_once(input, distance_line, ":")?.1;
+            return Err(ParseError::at(
+                input,
+                numbers.trim(),
+                format!("{} distances, one per time", times.len()),
+            ));
+        }
+
+        Ok((times.into_iter().zip(distances).collect(), (time, distance)))
+    }
+
+    fn part1((races, _): &Self::Parsed) -> Self::Answer1 {
+        races
+            .iter()
+            .map(|(time, distance)| count_wins(*time, *distance))
+            .product::<u64>()
+    }
+
+    fn part2((_, (time, distance)): &Self::Parsed) -> Self::Answer2 {
+        ways_to_win(*time, *distance)
+    }
+
+    /// `size` races of two-digit times, but no more than 4: part 2 reads
+    /// all of them as one race, which has to fit in a `u64`.
+    fn generate(rng: &mut Rng, size: usize) -> Option<String> {
+        let races = (0..size.min(4))
+            .map(|_| {
+                let time = rng.range(10..100);
+                (time, rng.range(time * time / 8..time * time / 4))
+            })
+            .collect::<Vec<_>>();
+
+        let line = |name: &str, numbers: Vec<u64>| {
+            let numbers = numbers
+                .iter()
+                .map(|number| format!("{:>4}", number))
+                .collect::<Vec<_>>();
+            format!("{:<9}{}\n", format!("{}:", name), numbers.join(" "))
+        };
+
+        Some(
+            line("Time", races.iter().map(|race| race.0).collect())
+                + &line("Distance", races.iter().map(|race| race.1).collect()),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        answers::assert_actual,
+        prop::{self, agree},
+        Part,
+    };
+
+    const SAMPLE_INPUT: &str = r"
+Time:      7  15   30
+Distance:  9  40  200
+";
+
+    #[test]
+    fn test_parse() {
+        let (races, race) = Day06::parse(SAMPLE_INPUT).unwrap();
+        assert_eq!(races, [(7, 9), (15, 40), (30, 200)]);
+        assert_eq!(race, (71530, 940200));
+
+        let error = |input| Day06::parse(input).unwrap_err().to_string();
+        assert_eq!(
+            error("Time: 7 15\nDistance: 9"),
+            "line 2, column 11: expected 2 distances, one per time, found '9'"
+        );
+        assert_eq!(
+            error("Time:\nDistance:"),
+            "line 1, column 6: expected a number, found nothing"
+        );
+        assert_eq!(
+            error("Time: 12345678901 23456789012\nDistance: 1 2"),
+            "line 1, column 7: expected numbers that fit in a u64 as one, \
+             found '12345678901 23456789012'"
+        );
+    }
+
+    #[test]
+    fn test_p1_sample() {
+        assert_eq!(Day06::p1(SAMPLE_INPUT), "288");
+    }
+
+    #[test]
+    fn test_p1_actual() {
+        assert_actual(2023, 6, Part::One, Day06::p1);
+    }
+
+    #[test]
+    fn test_ways_to_win() {
+        // Both roots are whole numbers: holding 10 or 20 only ties.
+        assert_eq!(ways_to_win(30, 200), 9);
+        assert_eq!(ways_to_win(4, 4), 0);
+        assert_eq!(ways_to_win(4, 3), 1);
+        assert_eq!(ways_to_win(0, 0), 0);
+    }
+
+    #[test]
+    fn test_ways_to_win_matches_brute_force() {
+        prop::check(
+            500,
+            |rng| {
+                let time = rng.range(0..200);
+                // Mostly distances near the best one, where the roots are.
+                let best = time * time / 4;
+                (time, rng.range(best.saturating_sub(2 * time)..best + 2))
+            },
+            |&(time, distance)| {
+                let times = prop::shrink_number(time)
+                    .into_iter()
+                    .map(|time| (time, distance));
+                let distances = prop::shrink_number(distance)
+                    .into_iter()
+                    .map(|distance| (time, distance));
+                times.chain(distances).collect()
+            },
+            |&(time, distance)| agree(ways_to_win(time, distance), count_wins(time, distance)),
+        );
+    }
+
+    #[test]
+    fn test_p2_sample() {
+        assert_eq!(Day06::p2(SAMPLE_INPUT), "71503");
+
+        // The third sample race on its own, whose roots the rounding of the
+        // earlier float formula counted as a win.
+        assert_eq!(Day06::p2("Time: 30\nDistance: 200"), "9");
+    }
+
+    #[test]
+    fn test_p2_actual() {
+        assert_actual(2023, 6, Part::Two, Day06::p2);
+    }
+}
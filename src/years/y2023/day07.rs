@@ -0,0 +1,272 @@
+use std::collections::HashMap;
+
+use crate::{
+    parse::{self, ParseError},
+    rng::Rng,
+    trace::{event, Tracer},
+    Answer, Part, Solution,
+};
+
+#[derive(Clone, Copy, Debug)]
+enum ProblemPart {
+    Part1,
+    Part2,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord)]
+enum HandType {
+    HighCard,
+    OnePair,
+    TwoPair,
+    ThreeOfAKind,
+    FullHouse,
+    FourOfAKind,
+    FiveOfAKind,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord)]
+struct Hand {
+    hand_type: HandType,
+    hand: [u8; 5],
+}
+
+const LABELS: &str = "23456789TJQKA";
+const JACK_VALUE: u8 = 11;
+
+/// Card values in part 1 order, from 2 for '2' up to 14 for 'A'.
+type Cards = [u8; 5];
+
+fn parse_cards(input: &str, cards: &str) -> Result<Cards, ParseError> {
+    cards
+        .char_indices()
+        .map(|(index, c)| {
+            LABELS.find(c).map(|value| value as u8 + 2).ok_or_else(|| {
+                ParseError::at(
+                    input,
+                    &cards[index..index + c.len_utf8()],
+                    format!("a card label (one of {})", LABELS),
+                )
+            })
+        })
+        .collect::<Result<Vec<_>, _>>()?
+        .try_into()
+        .map_err(|_| ParseError::at(input, cards, "a hand of 5 cards"))
+}
+
+impl Hand {
+    fn from_cards(cards: &Cards, problem_part: ProblemPart) -> Self {
+        let num_jokers = match problem_part {
+            ProblemPart::Part1 => 0,
+            ProblemPart::Part2 => cards.iter().filter(|c| **c == JACK_VALUE).count() as i32,
+        };
+
+        const JOKER_VALUE: u8 = 1;
+
+        let hand = cards.map(|c| match (c, problem_part) {
+            (JACK_VALUE, ProblemPart::Part2) => JOKER_VALUE,
+            _ => c,
+        });
+
+        let hand_type = {
+            let mut counters = hand
+                .iter()
+                .fold(HashMap::new(), |mut acc, current| {
+                    if *current != JOKER_VALUE {
+                        acc.entry(current).and_modify(|c| *c += 1).or_insert(1);
+                    }
+                    acc
+                })
+                .into_values()
+                .collect::<Vec<_>>();
+
+            counters.sort();
+            let len = counters.len();
+
+            // we need to check len == 0, otherwise len - 1 is an underflow
+            if len == 0 {
+                // all are jokers
+                HandType::FiveOfAKind
+            } else {
+                // hint from reddit: just use jokers on the label that has the most cards
+                counters[len - 1] += num_jokers;
+
+                if counters.contains(&5) {
+                    HandType::FiveOfAKind
+                } else if counters.contains(&4) {
+                    HandType::FourOfAKind
+                } else if counters.contains(&3) && counters.contains(&2) {
+                    HandType::FullHouse
+                } else if counters.contains(&3) {
+                    HandType::ThreeOfAKind
+                } else if counters.iter().filter(|v| **v == 2).count() == 2 {
+                    HandType::TwoPair
+                } else if counters.contains(&2) {
+                    HandType::OnePair
+                } else {
+                    HandType::HighCard
+                }
+            }
+        };
+
+        Self { hand, hand_type }
+    }
+}
+
+fn labels(cards: &Cards) -> String {
+    cards
+        .iter()
+        .map(|value| LABELS.as_bytes()[*value as usize - 2] as char)
+        .collect()
+}
+
+fn solve(input: &[(Cards, u32)], problem_part: ProblemPart, tracer: &mut dyn Tracer) -> u32 {
+    let mut hands = input
+        .iter()
+        .map(|(cards, bid)| {
+            let hand = Hand::from_cards(cards, problem_part);
+            event!(
+                tracer,
+                "hand",
+                cards = labels(cards),
+                hand_type = format!("{:?}", hand.hand_type),
+                bid = bid,
+            );
+            (hand, *bid)
+        })
+        .collect::<Vec<_>>();
+
+    hands.sort();
+
+    hands
+        .into_iter()
+        .enumerate()
+        .map(|(rank, (_, bid))| (rank as u32 + 1) * bid)
+        .sum::<u32>()
+}
+
+pub struct Day07;
+
+impl Solution for Day07 {
+    type Parsed = Vec<(Cards, u32)>;
+    type Answer1 = u32;
+    type Answer2 = u32;
+
+    fn parse(input: &str) -> Result<Self::Parsed, ParseError> {
+        input
+            .trim()
+            .lines()
+            .map(|line| {
+                let (cards, bid) = parse::split_once(input, line, " ")?;
+                Ok((parse_cards(input, cards)?, parse::number(input, bid)?))
+            })
+            .collect()
+    }
+
+    fn part1(hands: &Self::Parsed) -> Self::Answer1 {
+        solve(hands, ProblemPart::Part1, &mut ())
+    }
+
+    fn part2(hands: &Self::Parsed) -> Self::Answer2 {
+        solve(hands, ProblemPart::Part2, &mut ())
+    }
+
+    /// Shows the type of every hand.
+    fn explain(
+        hands: &Self::Parsed,
+        part: Part,
+        tracer: &mut dyn Tracer,
+    ) -> Option<Result<String, String>> {
+        let problem_part = match part {
+            Part::One => ProblemPart::Part1,
+            Part::Two => ProblemPart::Part2,
+        };
+
+        Some(solve(hands, problem_part, tracer).to_answer())
+    }
+
+    /// `size` hands with bids up to 1000. Labels repeat more often than at
+    /// random, so that every hand type shows up.
+    fn generate(rng: &mut Rng, size: usize) -> Option<String> {
+        let labels = LABELS.chars().collect::<Vec<_>>();
+
+        let hands = (0..size).map(|_| {
+            let picks = (0..rng.range(1..6))
+                .map(|_| *rng.choose(&labels))
+                .collect::<Vec<_>>();
+            let cards = (0..5).map(|_| *rng.choose(&picks)).collect::<String>();
+            format!("{} {}\n", cards, rng.range(1..1001))
+        });
+
+        Some(hands.collect())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::answers::assert_actual;
+
+    const SAMPLE_INPUT: &str = r"
+32T3K 765
+T55J5 684
+KK677 28
+KTJJT 220
+QQQJA 483
+";
+
+    #[test]
+    fn test_p1_sample() {
+        assert_eq!(Day07::p1(SAMPLE_INPUT), "6440");
+    }
+
+    #[test]
+    fn test_p1_actual() {
+        assert_actual(2023, 7, Part::One, Day07::p1);
+    }
+
+    #[test]
+    fn test_parse_error() {
+        let error = Day07::parse("32T3K 765\nT55X5 684").err().unwrap();
+        assert_eq!((error.line, error.column), (2, 4));
+        assert_eq!(error.found, "X");
+
+        let error = Day07::parse("32T3K 765\nT55J 684").err().unwrap();
+        assert_eq!((error.line, error.column), (2, 1));
+        assert_eq!(error.expected, "a hand of 5 cards");
+    }
+
+    #[test]
+    fn test_explain() {
+        let hands = Day07::parse(SAMPLE_INPUT).unwrap();
+        let hand_types = |part| {
+            let mut events = vec![];
+            Day07::explain(&hands, part, &mut events);
+            events
+                .into_iter()
+                .map(|event| event.to_string())
+                .collect::<Vec<_>>()
+        };
+
+        assert_eq!(
+            hand_types(Part::One)[..2],
+            [
+                "hand cards=32T3K hand_type=OnePair bid=765",
+                "hand cards=T55J5 hand_type=ThreeOfAKind bid=684",
+            ]
+        );
+        assert_eq!(
+            hand_types(Part::Two)[1],
+            "hand cards=T55J5 hand_type=FourOfAKind bid=684"
+        );
+    }
+
+    #[test]
+    fn test_p2_sample() {
+        assert_eq!(Day07::p2(SAMPLE_INPUT), "5905");
+    }
+
+    #[test]
+    fn test_p2_actual() {
+        assert_actual(2023, 7, Part::Two, Day07::p2);
+    }
+}
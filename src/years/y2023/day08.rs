@@ -0,0 +1,320 @@
+use std::collections::{HashMap, HashSet};
+
+use crate::{
+    math,
+    parse::{self, ParseError},
+    rng::Rng,
+    Solution,
+};
+
+#[derive(Clone, Copy)]
+enum Lookup {
+    Left,
+    Right,
+}
+
+impl Lookup {
+    fn parse_all(input: &str, instructions: &str) -> Result<Vec<Self>, ParseError> {
+        instructions
+            .trim()
+            .char_indices()
+            .map(|(index, c)| match c {
+                'L' => Ok(Lookup::Left),
+                'R' => Ok(Lookup::Right),
+                _ => Err(ParseError::at(
+                    input,
+                    &instructions.trim()[index..index + c.len_utf8()],
+                    "'L' or 'R'",
+                )),
+            })
+            .collect()
+    }
+}
+
+pub struct Map {
+    instructions: Vec<Lookup>,
+    nodes: HashMap<String, (String, String)>,
+}
+
+impl Map {
+    fn parse_input(input: &str) -> Result<Self, ParseError> {
+        let (instructions, nodes) = parse::split_once(input, input.trim(), "\n\n")?;
+        let instructions = Lookup::parse_all(input, instructions)?;
+        let nodes = nodes
+            .lines()
+            .map(|line| {
+                let (src, dest) = parse::split_once(input, line, "=")?;
+                let dest = dest.trim();
+                let dest = dest
+                    .strip_prefix('(')
+                    .and_then(|dest| dest.strip_suffix(')'))
+                    .ok_or_else(|| ParseError::at(input, dest, "(LEFT, RIGHT)"))?;
+
+                let (left, right) = parse::split_once(input, dest, ",")?;
+                Ok((src.trim(), left.trim(), right.trim()))
+            })
+            .collect::<Result<Vec<_>, ParseError>>()?;
+
+        // Every step has to lead somewhere.
+        let defined = nodes.iter().map(|(src, _, _)| *src).collect::<HashSet<_>>();
+        if let Some(missing) = nodes
+            .iter()
+            .flat_map(|(_, left, right)| [*left, *right])
+            .find(|dest| !defined.contains(dest))
+        {
+            return Err(ParseError::at(input, missing, "a defined node"));
+        }
+
+        let nodes = nodes
+            .into_iter()
+            .map(|(src, left, right)| (src.to_string(), (left.to_string(), right.to_string())))
+            .collect();
+
+        Ok(Self {
+            instructions,
+            nodes,
+        })
+    }
+
+    /// How many steps it takes from `start` to a node that `is_goal`. After
+    /// as many steps as there are pairs of a node and an instruction, the
+    /// walk has come back to one of them, and then never reaches a goal.
+    fn steps_to_goal(&self, start: &str, is_goal: impl Fn(&str) -> bool) -> Result<u64, String> {
+        let limit = (self.nodes.len() * self.instructions.len()) as u64;
+        let mut count = 0;
+        let mut step = 0;
+        let mut node = start;
+
+        while !is_goal(node) {
+            if count == limit {
+                return Err(format!("{} never reaches its goal", start));
+            }
+            count += 1;
+
+            let direction = self.instructions[step];
+            step = (step + 1) % self.instructions.len();
+
+            node = {
+                let node_content = &self.nodes[node];
+                match direction {
+                    Lookup::Left => &node_content.0,
+                    Lookup::Right => &node_content.1,
+                }
+            };
+        }
+
+        Ok(count)
+    }
+}
+
+pub struct Day08;
+
+impl Solution for Day08 {
+    type Parsed = Map;
+    type Answer1 = Result<u64, String>;
+    type Answer2 = Result<u64, String>;
+
+    fn parse(input: &str) -> Result<Self::Parsed, ParseError> {
+        Map::parse_input(input)
+    }
+
+    fn part1(map: &Self::Parsed) -> Self::Answer1 {
+        if !map.nodes.contains_key("AAA") {
+            return Err("there is no node AAA to start from".to_string());
+        }
+
+        map.steps_to_goal("AAA", |node| node == "ZZZ")
+    }
+
+    fn part2(map: &Self::Parsed) -> Self::Answer2 {
+        let all_cycles = map
+            .nodes
+            .keys()
+            .filter(|k| k.ends_with('A'))
+            .map(|node| map.steps_to_goal(node, |node| node.ends_with('Z')))
+            .collect::<Result<Vec<_>, _>>()?;
+        if all_cycles.is_empty() {
+            return Err("there is no node ending in A to start from".to_string());
+        }
+
+        // Every ghost reaches its goal again after exactly the same number
+        // of steps, so they all line up at the least common multiple.
+        all_cycles
+            .iter()
+            .try_fold(1, |acc, cycle| math::checked_lcm(acc, *cycle))
+            .ok_or_else(|| "the ghosts only line up after more than u64::MAX steps".to_string())
+    }
+
+    /// A network of about `size` nodes, at most what three-letter names
+    /// allow, walked by 6 ghosts. Each ghost's loop has both a left and a
+    /// right node at every step but its goal, so that the goal comes back
+    /// after the same number of steps whatever the instructions say, as in
+    /// the actual inputs.
+    fn generate(rng: &mut Rng, size: usize) -> Option<String> {
+        const GHOSTS: usize = 6;
+        const PRIMES: [usize; 12] = [2, 3, 5, 7, 11, 13, 17, 19, 23, 29, 31, 37];
+
+        let mut primes = PRIMES.to_vec();
+        rng.shuffle(&mut primes);
+        let primes = &primes[..GHOSTS];
+        let unit = (size.min(15_000) / (2 * primes.iter().sum::<usize>())).max(1);
+
+        let letter = |index: usize| (b'A' + index as u8) as char;
+        // Only the starts and goals end in A and Z, the others in B to Y.
+        let mut names = (0..26 * 26 * 24)
+            .map(|i| {
+                format!(
+                    "{}{}{}",
+                    letter(i / 26 / 24),
+                    letter(i / 24 % 26),
+                    letter(i % 24 + 1)
+                )
+            })
+            .collect::<Vec<_>>();
+        rng.shuffle(&mut names);
+        let mut prefixes = (0..26 * 26)
+            .map(|i| format!("{}{}", letter(i / 26), letter(i % 26)))
+            .filter(|prefix| prefix != "AA" && prefix != "ZZ")
+            .collect::<Vec<_>>();
+        rng.shuffle(&mut prefixes);
+
+        let mut nodes = vec![];
+        primes.iter().enumerate().for_each(|(ghost, prime)| {
+            let (start, goal) = match ghost {
+                0 => ("AAA".to_string(), "ZZZ".to_string()),
+                _ => (prefixes.pop().unwrap() + "A", prefixes.pop().unwrap() + "Z"),
+            };
+
+            // Steps 1 to length - 1 of the loop have two nodes each, both
+            // leading to the two of the next step in either order.
+            let length = unit * prime;
+            let steps = (1..length)
+                .map(|_| (names.pop().unwrap(), names.pop().unwrap()))
+                .collect::<Vec<_>>();
+            let mut next = |step: usize| match steps.get(step) {
+                Some((left, right)) if rng.chance(1, 2) => (right.clone(), left.clone()),
+                Some((left, right)) => (left.clone(), right.clone()),
+                None => (goal.clone(), goal.clone()),
+            };
+
+            nodes.push((start.clone(), next(0)));
+            nodes.push((goal.clone(), next(0)));
+            (0..steps.len()).for_each(|step| {
+                let (left, right) = &steps[step];
+                nodes.push((left.clone(), next(step + 1)));
+                nodes.push((right.clone(), next(step + 1)));
+            });
+        });
+        rng.shuffle(&mut nodes);
+
+        let instructions = (0..rng.range(10..300))
+            .map(|_| *rng.choose(&['L', 'R']))
+            .collect::<String>();
+        let nodes = nodes
+            .into_iter()
+            .map(|(node, (left, right))| format!("{} = ({}, {})\n", node, left, right))
+            .collect::<String>();
+
+        Some(format!("{}\n\n{}", instructions, nodes))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{answers::assert_actual, Part};
+
+    const SAMPLE_INPUT: &str = r"
+RL
+
+AAA = (BBB, CCC)
+BBB = (DDD, EEE)
+CCC = (ZZZ, GGG)
+DDD = (DDD, DDD)
+EEE = (EEE, EEE)
+GGG = (GGG, GGG)
+ZZZ = (ZZZ, ZZZ)
+";
+    const SAMPLE_INPUT_2: &str = r"
+LLR
+
+AAA = (BBB, BBB)
+BBB = (AAA, ZZZ)
+ZZZ = (ZZZ, ZZZ)
+";
+    const SAMPLE_INPUT_3: &str = r"
+LR
+
+11A = (11B, XXX)
+11B = (XXX, 11Z)
+11Z = (11B, XXX)
+22A = (22B, XXX)
+22B = (22C, 22C)
+22C = (22Z, 22Z)
+22Z = (22B, 22B)
+XXX = (XXX, XXX)
+";
+
+    #[test]
+    fn test_p1_sample() {
+        assert_eq!(Day08::p1(SAMPLE_INPUT), "2");
+        assert_eq!(Day08::p1(SAMPLE_INPUT_2), "6");
+    }
+
+    #[test]
+    fn test_p1_actual() {
+        assert_actual(2023, 8, Part::One, Day08::p1);
+    }
+
+    #[test]
+    fn test_parse_error() {
+        let error = Day08::parse("LRX\n\nAAA = (AAA, AAA)").err().unwrap();
+        assert_eq!((error.line, error.column), (1, 3));
+        assert_eq!(error.expected, "'L' or 'R'");
+
+        let error = Day08::parse("LR\n\nAAA = (AAA, AAA)\nBBB = BBB")
+            .err()
+            .unwrap();
+        assert_eq!((error.line, error.column), (4, 7));
+        assert_eq!(error.found, "BBB");
+
+        let error = Day08::parse("LR\n\nAAA = (AAA, BBB)").err().unwrap();
+        assert_eq!((error.line, error.column), (3, 13));
+        assert_eq!(error.expected, "a defined node");
+
+        let map = Day08::parse(SAMPLE_INPUT_3).unwrap();
+        assert_eq!(
+            Day08::part1(&map),
+            Err("there is no node AAA to start from".to_string())
+        );
+    }
+
+    #[test]
+    fn test_unreachable_goal() {
+        let map = Day08::parse("L\n\nAAA = (AAA, AAA)").unwrap();
+        assert_eq!(
+            Day08::part1(&map),
+            Err("AAA never reaches its goal".to_string())
+        );
+        assert_eq!(
+            Day08::part2(&map),
+            Err("AAA never reaches its goal".to_string())
+        );
+
+        let map = Day08::parse("LR\n\nBBB = (ZZZ, ZZZ)\nZZZ = (ZZZ, ZZZ)").unwrap();
+        assert_eq!(
+            Day08::part2(&map),
+            Err("there is no node ending in A to start from".to_string())
+        );
+    }
+
+    #[test]
+    fn test_p2_sample() {
+        assert_eq!(Day08::p2(SAMPLE_INPUT_3), "6");
+    }
+
+    #[test]
+    fn test_p2_actual() {
+        assert_actual(2023, 8, Part::Two, Day08::p2);
+    }
+}
@@ -0,0 +1,119 @@
+use crate::{
+    parse::{self, ParseError},
+    rng::Rng,
+    Solution,
+};
+
+fn extrapolate_once(history: &[i64]) -> (i64, i64) {
+    let mut diffs = vec![history.to_vec()];
+
+    while !diffs.iter().next_back().unwrap().iter().all(|x| *x == 0) {
+        diffs.push(
+            diffs
+                .iter()
+                .next_back()
+                .unwrap()
+                .windows(2)
+                .map(|vals| vals[1] - vals[0])
+                .collect::<Vec<_>>(),
+        );
+    }
+
+    diffs.iter().rev().fold((0, 0), |acc, diff| {
+        (
+            acc.0 + diff.iter().next_back().unwrap_or(&0),
+            diff.iter().next().unwrap_or(&0) - acc.1,
+        )
+    })
+}
+
+fn solve(histories: &[Vec<i64>]) -> (i64, i64) {
+    histories
+        .iter()
+        .map(|line| extrapolate_once(line))
+        .fold((0, 0), |acc, current| {
+            (acc.0 + current.0, acc.1 + current.1)
+        })
+}
+
+pub struct Day09;
+
+impl Solution for Day09 {
+    type Parsed = Vec<Vec<i64>>;
+    type Answer1 = i64;
+    type Answer2 = i64;
+
+    fn parse(input: &str) -> Result<Self::Parsed, ParseError> {
+        input
+            .trim()
+            .lines()
+            .map(|line| {
+                line.split_whitespace()
+                    .map(|x| parse::number::<i64>(input, x))
+                    .collect::<Result<Vec<_>, _>>()
+            })
+            .collect()
+    }
+
+    fn part1(histories: &Self::Parsed) -> Self::Answer1 {
+        solve(histories).0
+    }
+
+    fn part2(histories: &Self::Parsed) -> Self::Answer2 {
+        solve(histories).1
+    }
+
+    /// `size` histories of 21 values of a polynomial of degree up to 6 with
+    /// small integer coefficients.
+    fn generate(rng: &mut Rng, size: usize) -> Option<String> {
+        let histories = (0..size).map(|_| {
+            let coefficients = (0..rng.range(1..8))
+                .map(|_| rng.range(0..11) as i64 - 5)
+                .collect::<Vec<_>>();
+            let values = (0..21)
+                .map(|x| {
+                    coefficients
+                        .iter()
+                        .rev()
+                        .fold(0, |acc, coefficient| acc * x + coefficient)
+                        .to_string()
+                })
+                .collect::<Vec<_>>();
+            values.join(" ") + "\n"
+        });
+
+        Some(histories.collect())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{answers::assert_actual, Part};
+
+    const SAMPLE_INPUT: &str = r"
+0 3 6 9 12 15
+1 3 6 10 15 21
+10 13 16 21 30 45
+";
+
+    #[test]
+    fn test_p1_sample() {
+        assert_eq!(Day09::p1(SAMPLE_INPUT), "114");
+    }
+
+    #[test]
+    fn test_p1_actual() {
+        assert_actual(2023, 9, Part::One, Day09::p1);
+    }
+
+    #[test]
+    fn test_p2_sample() {
+        assert_eq!(Day09::p2(SAMPLE_INPUT), "2");
+    }
+
+    #[test]
+    fn test_p2_actual() {
+        assert_actual(2023, 9, Part::Two, Day09::p2);
+    }
+}
@@ -0,0 +1,468 @@
+use crate::{
+    dir::Dir4,
+    grid::{Grid, Pos},
+    parse::{self, ParseError},
+    polygon::{Location, Polygon},
+    render::{Canvas, Color},
+    rng::Rng,
+    vec2::Vec2,
+    Solution,
+};
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+enum Pipe {
+    None,
+    Starting,
+    NS,
+    EW,
+    NE,
+    SE,
+    NW,
+    SW,
+}
+
+impl Pipe {
+    fn parse(ch: char) -> Option<Self> {
+        match ch {
+            '.' => Some(Pipe::None),
+            'S' => Some(Pipe::Starting),
+            '|' => Some(Pipe::NS),
+            '-' => Some(Pipe::EW),
+            'L' => Some(Pipe::NE),
+            'F' => Some(Pipe::SE),
+            'J' => Some(Pipe::NW),
+            '7' => Some(Pipe::SW),
+            _ => None,
+        }
+    }
+}
+
+pub struct Map {
+    content: Grid<Pipe>,
+    starting_point: Vec2,
+    main_loop: Vec<Vec2>,
+}
+
+impl Map {
+    fn parse_input(input: &str) -> Result<Self, ParseError> {
+        let content = Grid::parse(input, input.trim(), "a pipe (one of .S|-LFJ7)", Pipe::parse)?;
+
+        let starting_point = content
+            .find(|pipe| *pipe == Pipe::Starting)
+            .map(Vec2::from)
+            .ok_or_else(|| {
+                ParseError::at(input, parse::end(input.trim()), "a starting tile 'S'")
+            })?;
+
+        fn fix_starting_point_pipe(mut map: Map) -> Option<Map> {
+            let [up, right, down, left] =
+                Dir4::ALL.map(|dir| map.has_exit(map.starting_point.step(dir), dir.reverse()));
+
+            let starting_point_pipe = if left && up {
+                Pipe::NW
+            } else if left && down {
+                Pipe::SW
+            } else if right && up {
+                Pipe::NE
+            } else if right && down {
+                Pipe::SE
+            } else if up && down {
+                Pipe::NS
+            } else if left && right {
+                Pipe::EW
+            } else {
+                return None;
+            };
+            *map.get_mut(map.starting_point).unwrap() = starting_point_pipe;
+            Some(map)
+        }
+
+        let start_error = |expected| {
+            let line = input.trim().lines().nth(starting_point.y as usize).unwrap();
+            let (index, _) = line.char_indices().nth(starting_point.x as usize).unwrap();
+            ParseError::at(input, &line[index..=index], expected)
+        };
+
+        let mut map = fix_starting_point_pipe(Self {
+            content,
+            starting_point,
+            main_loop: vec![],
+        })
+        .ok_or_else(|| start_error("a start connected to two pipes"))?;
+
+        map.main_loop = map
+            .find_loop()
+            .ok_or_else(|| start_error("a start on a closed loop of pipes"))?;
+
+        Ok(map)
+    }
+
+    fn get(&self, pos: Vec2) -> Option<&Pipe> {
+        self.content.get(self.content.checked_pos(pos)?)
+    }
+
+    fn get_mut(&mut self, pos: Vec2) -> Option<&mut Pipe> {
+        let pos = self.content.checked_pos(pos)?;
+        self.content.get_mut(pos)
+    }
+
+    fn has_exit(&self, pos: Vec2, dir: Dir4) -> bool {
+        self.get(pos)
+            .map(|ch| match dir {
+                Dir4::Up => matches!(*ch, Pipe::NS | Pipe::NE | Pipe::NW),
+                Dir4::Down => matches!(*ch, Pipe::NS | Pipe::SE | Pipe::SW),
+                Dir4::Left => matches!(*ch, Pipe::EW | Pipe::NW | Pipe::SW),
+                Dir4::Right => matches!(*ch, Pipe::EW | Pipe::NE | Pipe::SE),
+            })
+            .unwrap_or(false)
+    }
+
+    fn is_connected(&self, pos: Vec2, dir: Dir4) -> bool {
+        self.has_exit(pos, dir) && self.has_exit(pos.step(dir), dir.reverse())
+    }
+
+    /// The tiles of the loop through the start, in the order they are
+    /// walked, or `None` if following the pipes leads to a dead end.
+    fn find_loop(&self) -> Option<Vec<Vec2>> {
+        let mut tiles = vec![self.starting_point];
+        let mut dir = Dir4::ALL
+            .into_iter()
+            .find(|dir| self.is_connected(self.starting_point, *dir))?;
+
+        loop {
+            let pos = tiles.last().unwrap().step(dir);
+            if pos == self.starting_point {
+                return Some(tiles);
+            }
+
+            dir = Dir4::ALL
+                .into_iter()
+                .find(|next| *next != dir.reverse() && self.is_connected(pos, *next))?;
+            tiles.push(pos);
+        }
+    }
+}
+
+pub struct Day10;
+
+impl Solution for Day10 {
+    type Parsed = Map;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Parsed, ParseError> {
+        Map::parse_input(input)
+    }
+
+    fn part1(map: &Self::Parsed) -> Self::Answer1 {
+        map.main_loop.len() / 2
+    }
+
+    fn part2(map: &Self::Parsed) -> Self::Answer2 {
+        Polygon::new(map.main_loop.clone()).interior_points() as usize
+    }
+
+    /// The main loop, with the tiles it encloses highlighted.
+    fn render(map: &Self::Parsed) -> Option<Canvas> {
+        let polygon = Polygon::new(map.main_loop.clone());
+        let mut canvas = Canvas::from_grid(&map.content, |pipe| match pipe {
+            Pipe::None => Color::WHITE,
+            _ => Color::grey(200),
+        });
+
+        map.content
+            .positions()
+            .map(Vec2::from)
+            .filter(|pos| polygon.locate(*pos) == Location::Inside)
+            .for_each(|pos| canvas.fill(pos, Color::YELLOW));
+        canvas.stroke(map.main_loop.iter().copied(), Color::RED, true);
+
+        Some(canvas)
+    }
+
+    /// A `size` by `size` field of junk pipes around a loop. The loop goes
+    /// around a random tree of 2 by 2 blocks, visiting every tile of them,
+    /// and is then stretched to twice the size so that it encloses tiles.
+    /// The start is one of its corners.
+    fn generate(rng: &mut Rng, size: usize) -> Option<String> {
+        let size = size.max(7);
+        let blocks = (size + 1) / 4;
+
+        let mut in_tree = Grid::new(blocks, blocks, false);
+        let root = (rng.below(blocks), rng.below(blocks));
+        in_tree[root] = true;
+        let mut frontier = in_tree
+            .neighbours4(root)
+            .map(|next| (root, next))
+            .collect::<Vec<_>>();
+        let mut branches = vec![];
+        while branches.len() < blocks * blocks / 2 && !frontier.is_empty() {
+            let (from, to) = frontier.swap_remove(rng.below(frontier.len()));
+            if !in_tree[to] {
+                in_tree[to] = true;
+                branches.push((from, to));
+                frontier.extend(in_tree.neighbours4(to).map(|next| (to, next)));
+            }
+        }
+
+        // The exits of every tile, in the order of `Dir4::ALL`.
+        let mut exits = Grid::new(2 * blocks, 2 * blocks, [false; 4]);
+        let mut link = |a: Pos, b: Pos, open: bool| {
+            let offset = Vec2::from(b) - Vec2::from(a);
+            let dir = Dir4::ALL
+                .into_iter()
+                .position(|dir| dir.offset() == offset)
+                .unwrap();
+            exits[a][dir] = open;
+            exits[b][(dir + 2) % 4] = open;
+        };
+        let corners = |(x, y): Pos| {
+            let (x, y) = (2 * x, 2 * y);
+            [(x, y), (x + 1, y), (x, y + 1), (x + 1, y + 1)]
+        };
+
+        in_tree
+            .positions()
+            .filter(|block| in_tree[*block])
+            .for_each(|block| {
+                let [top_left, top_right, bottom_left, bottom_right] = corners(block);
+                link(top_left, top_right, true);
+                link(bottom_left, bottom_right, true);
+                link(top_left, bottom_left, true);
+                link(top_right, bottom_right, true);
+            });
+        // Opening the wall between two blocks joins their loops into one.
+        branches.into_iter().for_each(|(from, to)| {
+            let (a, b) = (from.min(to), from.max(to));
+            let [_, a_top_right, a_bottom_left, a_bottom_right] = corners(a);
+            let [b_top_left, b_top_right, b_bottom_left, _] = corners(b);
+            if a.0 < b.0 {
+                link(a_top_right, a_bottom_right, false);
+                link(b_top_left, b_bottom_left, false);
+                link(a_top_right, b_top_left, true);
+                link(a_bottom_right, b_bottom_left, true);
+            } else {
+                link(a_bottom_left, a_bottom_right, false);
+                link(b_top_left, b_top_right, false);
+                link(a_bottom_left, b_top_left, true);
+                link(a_bottom_right, b_top_right, true);
+            }
+        });
+
+        let mut tiles = Grid::new(size, size, ' ');
+        exits.iter().for_each(|((x, y), exits)| {
+            let pipe = match exits {
+                [true, false, true, false] => '|',
+                [false, true, false, true] => '-',
+                [true, true, false, false] => 'L',
+                [true, false, false, true] => 'J',
+                [false, false, true, true] => '7',
+                [false, true, true, false] => 'F',
+                _ => return,
+            };
+            tiles[(2 * x, 2 * y)] = pipe;
+            if exits[1] {
+                tiles[(2 * x + 1, 2 * y)] = '-';
+            }
+            if exits[2] {
+                tiles[(2 * x, 2 * y + 1)] = '|';
+            }
+        });
+
+        let on_loop = tiles.map(|tile| *tile != ' ');
+        let loop_corners = tiles
+            .iter()
+            .filter(|(_, tile)| matches!(tile, 'L' | 'J' | '7' | 'F'))
+            .map(|(pos, _)| pos)
+            .collect::<Vec<_>>();
+        let start = *rng.choose(&loop_corners);
+
+        tiles
+            .positions()
+            .collect::<Vec<_>>()
+            .into_iter()
+            .for_each(|pos| {
+                if tiles[pos] == ' ' {
+                    tiles[pos] = *rng.choose(&['.', '.', '.', '|', '-', 'L', 'J', '7', 'F']);
+                }
+            });
+        // Junk next to the start must not look connected to it.
+        tiles
+            .neighbours4(start)
+            .filter(|pos| !on_loop[*pos])
+            .collect::<Vec<_>>()
+            .into_iter()
+            .for_each(|pos| tiles[pos] = '.');
+        tiles[start] = 'S';
+
+        Some(tiles.to_string())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{answers::assert_actual, Part};
+
+    const SAMPLE_INPUT_P1: [&str; 4] = [
+        r"
+.....
+.S-7.
+.|.|.
+.L-J.
+.....
+",
+        r"
+-L|F7
+7S-7|
+L|7||
+-L-J|
+L|-JF
+",
+        r"
+..F7.
+.FJ|.
+SJ.L7
+|F--J
+LJ...
+",
+        r"
+7-F7-
+.FJ|7
+SJLL7
+|F--J
+LJ.LJ
+",
+    ];
+
+    #[test]
+    fn test_p1_sample() {
+        assert_eq!(Day10::p1(SAMPLE_INPUT_P1[0]), "4");
+        assert_eq!(Day10::p1(SAMPLE_INPUT_P1[1]), "4");
+        assert_eq!(Day10::p1(SAMPLE_INPUT_P1[2]), "8");
+        assert_eq!(Day10::p1(SAMPLE_INPUT_P1[3]), "8");
+    }
+
+    #[test]
+    fn test_straight_start() {
+        assert_eq!(Day10::p1(".F-7.\n.S.|.\n.L-J."), "4");
+        assert_eq!(Day10::p1(".FS7.\n.|.|.\n.L-J."), "4");
+    }
+
+    #[test]
+    fn test_p1_actual() {
+        assert_actual(2023, 10, Part::One, Day10::p1);
+    }
+
+    #[test]
+    fn test_parse_error() {
+        let error = Day10::parse(".....\n.S-7.\n.|x|.").err().unwrap();
+        assert_eq!((error.line, error.column), (3, 3));
+        assert_eq!(error.found, "x");
+
+        let error = Day10::parse(".....\n.S-7.\n.|.").err().unwrap();
+        assert_eq!((error.line, error.column), (3, 4));
+
+        let error = Day10::parse("...\n.S.\n...").err().unwrap();
+        assert_eq!((error.line, error.column), (2, 2));
+        assert_eq!(error.expected, "a start connected to two pipes");
+
+        let error = Day10::parse("S-7\n|..").err().unwrap();
+        assert_eq!((error.line, error.column), (1, 1));
+        assert_eq!(error.expected, "a start on a closed loop of pipes");
+    }
+
+    const SAMPLE_INPUT_P2: [&str; 6] = [
+        r"
+S--7
+|..|
+|..|
+L--J
+",
+        r"
+...........
+.S-------7.
+.|F-----7|.
+.||.....||.
+.||.....||.
+.|L-7.F-J|.
+.|..|.|..|.
+.L--J.L--J.
+...........
+",
+        r"
+..........
+.S------7.
+.|F----7|.
+.||....||.
+.||....||.
+.|L-7F-J|.
+.|..||..|.
+.L--JL--J.
+..........
+",
+        r"
+.F----7F7F7F7F-7....
+.|F--7||||||||FJ....
+.||.FJ||||||||L7....
+FJL7L7LJLJ||LJ.L-7..
+L--J.L7...LJS7F-7L7.
+....F-J..F7FJ|L7L7L7
+....L7.F7||L7|.L7L7|
+.....|FJLJ|FJ|F7|.LJ
+....FJL-7.||.||||...
+....L---J.LJ.LJLJ...
+",
+        r"
+FF7FSF7F7F7F7F7F---7
+L|LJ||||||||||||F--J
+FL-7LJLJ||||||LJL-77
+F--JF--7||LJLJ7F7FJ-
+L---JF-JLJ.||-FJLJJ7
+|F|F-JF---7F7-L7L|7|
+|FFJF7L7F-JF7|JL---7
+7-L-JL7||F7|L7F-7F7|
+L.L7LFJ|||||FJL7||LJ
+L7JLJL-JLJLJL--JLJ.L
+",
+        r"
+..S-7..
+..|.|..
+F-J.L-7
+|.....|
+L-7.F-J
+..|.|..
+..L-J..
+",
+    ];
+
+    #[test]
+    fn test_p2_sample() {
+        assert_eq!(Day10::p2(SAMPLE_INPUT_P2[0]), "4");
+        assert_eq!(Day10::p2(SAMPLE_INPUT_P2[1]), "4");
+        assert_eq!(Day10::p2(SAMPLE_INPUT_P2[2]), "4");
+        assert_eq!(Day10::p2(SAMPLE_INPUT_P2[3]), "8");
+        assert_eq!(Day10::p2(SAMPLE_INPUT_P2[4]), "10");
+        assert_eq!(Day10::p2(SAMPLE_INPUT_P2[5]), "9");
+    }
+
+    #[test]
+    fn test_enclosed_tiles() {
+        SAMPLE_INPUT_P2.iter().for_each(|input| {
+            let map = Day10::parse(input).unwrap();
+            let polygon = Polygon::new(map.main_loop.clone());
+            let inside = map
+                .content
+                .positions()
+                .filter(|pos| polygon.locate(Vec2::from(*pos)) == Location::Inside)
+                .count();
+
+            assert_eq!(inside.to_string(), Day10::p2(input));
+        });
+    }
+
+    #[test]
+    fn test_p2_actual() {
+        assert_actual(2023, 10, Part::Two, Day10::p2);
+    }
+}
@@ -1,27 +1,43 @@
 use std::collections::HashSet;
 
-const ACTUAL_INPUT: &str = include_str!("../../../actual_inputs/2023/11/input.txt");
-
-struct Universe {
+use crate::{
+    parse::{self, ParseError},
+    rng::Rng,
+    Solution,
+};
+
+#[derive(Clone)]
+pub struct Universe {
     galaxies: Vec<(usize, usize)>,
 }
 
 impl Universe {
-    fn parse(input: &str) -> Self {
-        Self {
-            galaxies: input
-                .trim()
-                .lines()
-                .enumerate()
-                .flat_map(|(y, line)| {
-                    line.trim()
-                        .chars()
-                        .enumerate()
-                        .filter(|(_, ch)| *ch == '#')
-                        .map(|(x, _)| (x, y))
-                        .collect::<Vec<_>>()
-                })
-                .collect(),
+    fn parse(input: &str) -> Result<Self, ParseError> {
+        let grid = parse::grid(input, input.trim(), "'#' or '.'", |ch| match ch {
+            '#' => Some(true),
+            '.' => Some(false),
+            _ => None,
+        })?;
+
+        let galaxies = grid
+            .iter()
+            .enumerate()
+            .flat_map(|(y, row)| {
+                row.iter()
+                    .enumerate()
+                    .filter(|(_, galaxy)| **galaxy)
+                    .map(move |(x, _)| (x, y))
+            })
+            .collect::<Vec<_>>();
+
+        if galaxies.is_empty() {
+            Err(ParseError::at(
+                input,
+                input.trim(),
+                "at least one galaxy '#'",
+            ))
+        } else {
+            Ok(Self { galaxies })
         }
     }
 
@@ -91,26 +107,66 @@ impl Universe {
     }
 }
 
-fn p1(input: &str) -> String {
-    let mut universe = Universe::parse(input);
-    universe.expand(1);
-    universe.get_galaxies_pairs_steps_sum().to_string()
-}
+pub struct Day11;
 
-fn p2(input: &str) -> String {
-    let mut universe = Universe::parse(input);
-    universe.expand(999_999);
-    universe.get_galaxies_pairs_steps_sum().to_string()
-}
+impl Solution for Day11 {
+    type Parsed = Universe;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Parsed, ParseError> {
+        Universe::parse(input)
+    }
 
-fn main() {
-    println!("{}", p1(ACTUAL_INPUT));
-    println!("{}", p2(ACTUAL_INPUT));
+    fn part1(universe: &Self::Parsed) -> Self::Answer1 {
+        let mut universe = universe.clone();
+        universe.expand(1);
+        universe.get_galaxies_pairs_steps_sum()
+    }
+
+    fn part2(universe: &Self::Parsed) -> Self::Answer2 {
+        let mut universe = universe.clone();
+        universe.expand(999_999);
+        universe.get_galaxies_pairs_steps_sum()
+    }
+
+    /// A `size` by `size` image with about one galaxy in 20 tiles, and one
+    /// row or column in 10 left empty to expand.
+    fn generate(rng: &mut Rng, size: usize) -> Option<String> {
+        let empty_rows = (0..size).map(|_| rng.chance(1, 10)).collect::<Vec<_>>();
+        let empty_columns = (0..size).map(|_| rng.chance(1, 10)).collect::<Vec<_>>();
+
+        let mut image = (0..size)
+            .map(|y| {
+                (0..size)
+                    .map(|x| {
+                        let galaxy = !empty_rows[y] && !empty_columns[x] && rng.chance(1, 20);
+                        if galaxy {
+                            '#'
+                        } else {
+                            '.'
+                        }
+                    })
+                    .collect::<Vec<_>>()
+            })
+            .collect::<Vec<_>>();
+        if !image.iter().flatten().any(|tile| *tile == '#') {
+            image[rng.below(size)][rng.below(size)] = '#';
+        }
+
+        Some(
+            image
+                .into_iter()
+                .map(|row| row.into_iter().collect::<String>() + "\n")
+                .collect(),
+        )
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::{answers::assert_actual, Part};
 
     const SAMPLE_INPUT: &str = r"
 ...#......
@@ -128,7 +184,7 @@ mod tests {
     #[test]
     fn test_parse() {
         assert_eq!(
-            Universe::parse(SAMPLE_INPUT).get_grid_display(),
+            Universe::parse(SAMPLE_INPUT).unwrap().get_grid_display(),
             SAMPLE_INPUT.trim().to_string()
         );
     }
@@ -149,7 +205,7 @@ mod tests {
 .........#...
 #....#.......
 ";
-        let mut universe = Universe::parse(SAMPLE_INPUT);
+        let mut universe = Universe::parse(SAMPLE_INPUT).unwrap();
         universe.expand(1);
         assert_eq!(
             universe.get_grid_display(),
@@ -159,23 +215,23 @@ mod tests {
 
     #[test]
     fn test_p1_sample() {
-        assert_eq!(p1(SAMPLE_INPUT), "374");
+        assert_eq!(Day11::p1(SAMPLE_INPUT), "374");
     }
 
     #[test]
     fn test_p1_actual() {
-        assert_eq!(p1(ACTUAL_INPUT), "9312968");
+        assert_actual(2023, 11, Part::One, Day11::p1);
     }
 
     #[test]
     fn test_p2_smaller_expand() {
         {
-            let mut universe = Universe::parse(SAMPLE_INPUT);
+            let mut universe = Universe::parse(SAMPLE_INPUT).unwrap();
             universe.expand(9);
             assert_eq!(universe.get_galaxies_pairs_steps_sum(), 1030);
         }
         {
-            let mut universe = Universe::parse(SAMPLE_INPUT);
+            let mut universe = Universe::parse(SAMPLE_INPUT).unwrap();
             universe.expand(99);
             assert_eq!(universe.get_galaxies_pairs_steps_sum(), 8410);
         }
@@ -183,11 +239,11 @@ mod tests {
 
     #[test]
     fn test_p2_sample() {
-        assert_eq!(p2(SAMPLE_INPUT), "82000210");
+        assert_eq!(Day11::p2(SAMPLE_INPUT), "82000210");
     }
 
     #[test]
     fn test_p2_actual() {
-        assert_eq!(p2(ACTUAL_INPUT), "597714117556");
+        assert_actual(2023, 11, Part::Two, Day11::p2);
     }
 }
@@ -0,0 +1,334 @@
+use std::collections::HashMap;
+
+use crate::{
+    parse::{self, ParseError},
+    rng::Rng,
+    Solution,
+};
+
+fn parse_record(input: &str, line: &str) -> Result<(String, Vec<usize>), ParseError> {
+    let (springs, count) = parse::split_once(input, line.trim(), " ")?;
+
+    if let Some((index, ch)) = springs
+        .char_indices()
+        .find(|(_, ch)| !matches!(ch, '.' | '#' | '?'))
+    {
+        let found = &springs[index..index + ch.len_utf8()];
+        return Err(ParseError::at(input, found, "'.', '#' or '?'"));
+    }
+
+    let springs = springs.trim().to_string();
+    let count = count
+        .trim()
+        .split(',')
+        .map(|x| parse::number::<usize>(input, x))
+        .collect::<Result<Vec<_>, _>>()?;
+    Ok((springs, count))
+}
+
+// taken from reddit: https://www.reddit.com/r/adventofcode/comments/18hg99r/2023_day_12_simple_tutorial_with_memoization/
+/*
+solve(springs, groups, cache, i):
+    if num groups is 0:
+        if any '#' remaining in springs return 0
+        else return 1
+
+    advance i to the next available '?' or '#'
+
+    if i > length of springs return 0
+
+    if (i, num groups) is in cache, return it
+
+    if we can fill the springs at i with the first group in groups:
+        recursively call with the groups after that at index: i + groupsize + 1
+
+    if the current spot is '?':
+        recursively call with current groups at the next index
+
+    add the result to the cache
+    return result
+*/
+fn solve(input: (String, Vec<usize>)) -> usize {
+    fn solve_recur(
+        springs: &String,
+        groups: &Vec<usize>,
+        cache: &mut HashMap<(usize, usize), usize>,
+        i: usize,
+        i_group: usize,
+    ) -> usize {
+        if i_group >= groups.len() {
+            if springs.chars().skip(i).any(|ch| ch == '#') {
+                0
+            } else {
+                1
+            }
+        } else {
+            let next = springs
+                .chars()
+                .enumerate()
+                .skip(i)
+                .find(|(_, ch)| *ch == '?' || *ch == '#');
+
+            if let Some((i, ch)) = next {
+                let cache_key = (i, groups.len() - i_group);
+
+                if cache.contains_key(&cache_key) {
+                    *cache.get(&cache_key).unwrap()
+                } else {
+                    let mut result = 0;
+
+                    let first_group_size = groups.get(i_group).unwrap();
+
+                    if (i + *first_group_size <= springs.len())
+                        && springs
+                            .chars()
+                            .skip(i)
+                            .take(*first_group_size)
+                            .all(|ch| ch == '?' || ch == '#')
+                        && springs
+                            .chars()
+                            .nth(i + *first_group_size)
+                            .map(|ch| ch != '#')
+                            .unwrap_or(true)
+                    {
+                        result += solve_recur(
+                            springs,
+                            groups,
+                            cache,
+                            i + *first_group_size + 1,
+                            i_group + 1,
+                        );
+                    }
+
+                    if ch == '?' {
+                        result += solve_recur(springs, groups, cache, i + 1, i_group);
+                    }
+
+                    cache.insert(cache_key, result);
+                    result
+                }
+            } else {
+                0
+            }
+        }
+    }
+
+    solve_recur(&input.0, &input.1, &mut HashMap::new(), 0, 0)
+}
+
+fn unfold(input: (String, Vec<usize>)) -> (String, Vec<usize>) {
+    (
+        [input.0]
+            .into_iter()
+            .cycle()
+            .take(5)
+            .collect::<Vec<_>>()
+            .join("?"),
+        [input.1].into_iter().cycle().take(5).flatten().collect(),
+    )
+}
+
+pub struct Day12;
+
+impl Solution for Day12 {
+    type Parsed = Vec<(String, Vec<usize>)>;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Parsed, ParseError> {
+        input
+            .trim()
+            .lines()
+            .map(|line| parse_record(input, line))
+            .collect()
+    }
+
+    fn part1(records: &Self::Parsed) -> Self::Answer1 {
+        records.iter().cloned().map(solve).sum::<usize>()
+    }
+
+    fn part2(records: &Self::Parsed) -> Self::Answer2 {
+        records
+            .iter()
+            .cloned()
+            .map(unfold)
+            .map(solve)
+            .sum::<usize>()
+    }
+
+    /// `size` rows of up to 20 springs, each with at least one
+    /// arrangement: the groups are read off the springs before some of
+    /// them are hidden behind a `?`.
+    fn generate(rng: &mut Rng, size: usize) -> Option<String> {
+        let rows = (0..size).map(|_| {
+            let length = rng.range(1..21) as usize;
+            let mut springs = (0..length)
+                .map(|_| if rng.chance(1, 2) { '#' } else { '.' })
+                .collect::<Vec<_>>();
+            if !springs.contains(&'#') {
+                springs[rng.below(length)] = '#';
+            }
+
+            let groups = springs
+                .split(|spring| *spring == '.')
+                .filter(|group| !group.is_empty())
+                .map(|group| group.len().to_string())
+                .collect::<Vec<_>>();
+            springs.iter_mut().for_each(|spring| {
+                if rng.chance(1, 2) {
+                    *spring = '?';
+                }
+            });
+
+            format!(
+                "{} {}\n",
+                springs.into_iter().collect::<String>(),
+                groups.join(",")
+            )
+        });
+
+        Some(rows.collect())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        answers::assert_actual,
+        prop::{self, agree},
+        Part,
+    };
+
+    /// Counts the arrangements by trying every way to fill in the `?`s.
+    fn enumerate(springs: &str, groups: &[usize]) -> usize {
+        let unknown = springs.matches('?').count();
+
+        (0..1u32 << unknown)
+            .filter(|assignment| {
+                let mut bit = 0;
+                let filled = springs
+                    .chars()
+                    .map(|ch| match ch {
+                        '?' => {
+                            bit += 1;
+                            if assignment >> (bit - 1) & 1 == 1 {
+                                '#'
+                            } else {
+                                '.'
+                            }
+                        }
+                        ch => ch,
+                    })
+                    .collect::<String>();
+
+                filled
+                    .split('.')
+                    .filter(|group| !group.is_empty())
+                    .map(str::len)
+                    .eq(groups.iter().copied())
+            })
+            .count()
+    }
+
+    const SAMPLE_INPUT: &str = r"
+???.### 1,1,3
+.??..??...?##. 1,1,3
+?#?#?#?#?#?#?#? 1,3,1,6
+????.#...#... 4,1,1
+????.######..#####. 1,6,5
+?###???????? 3,2,1
+";
+
+    #[test]
+    fn test_reddit_example() {
+        assert_eq!(solve(("??#???#?????.?".to_string(), vec![5, 1, 1])), 12);
+        assert_eq!(solve(("????????#???".to_string(), vec![2, 3])), 15);
+    }
+
+    #[test]
+    fn test_solve_matches_enumeration() {
+        prop::check(
+            500,
+            |rng| {
+                let springs = (0..rng.range(1..13))
+                    .map(|_| *rng.choose(&['.', '#', '?']))
+                    .collect::<String>();
+
+                // Groups read off the springs with the `?`s filled in at
+                // random fit at least one arrangement; otherwise any groups.
+                let groups = if rng.chance(3, 4) {
+                    springs
+                        .chars()
+                        .map(|ch| match ch {
+                            '?' if rng.chance(1, 2) => '#',
+                            '?' => '.',
+                            ch => ch,
+                        })
+                        .collect::<String>()
+                        .split('.')
+                        .filter(|group| !group.is_empty())
+                        .map(str::len)
+                        .collect()
+                } else {
+                    (0..rng.range(0..4)).map(|_| rng.below(4) + 1).collect()
+                };
+
+                (springs, groups)
+            },
+            |(springs, groups): &(String, Vec<usize>)| {
+                let fewer_springs = (0..springs.len()).map(|i| {
+                    let mut springs = springs.clone();
+                    springs.remove(i);
+                    (springs, groups.clone())
+                });
+                let known_springs = springs.match_indices('?').flat_map(|(i, _)| {
+                    ['.', '#'].map(|ch| {
+                        let mut springs = springs.clone();
+                        springs.replace_range(i..i + 1, &ch.to_string());
+                        (springs, groups.clone())
+                    })
+                });
+                let smaller_groups = prop::shrink_vec(groups, |size| {
+                    prop::shrink_number(*size as u64 - 1)
+                        .into_iter()
+                        .map(|size| size as usize + 1)
+                        .collect()
+                })
+                .into_iter()
+                .map(|groups| (springs.clone(), groups));
+
+                fewer_springs
+                    .chain(known_springs)
+                    .chain(smaller_groups)
+                    .collect()
+            },
+            |(springs, groups)| {
+                agree(
+                    solve((springs.clone(), groups.clone())),
+                    enumerate(springs, groups),
+                )
+            },
+        );
+    }
+
+    #[test]
+    fn test_p1_sample() {
+        assert_eq!(Day12::p1(SAMPLE_INPUT), "21");
+    }
+
+    #[test]
+    fn test_p1_actual() {
+        assert_actual(2023, 12, Part::One, Day12::p1);
+    }
+
+    #[test]
+    fn test_p2_sample() {
+        assert_eq!(Day12::p2(SAMPLE_INPUT), "525152");
+    }
+
+    #[test]
+    fn test_p2_actual() {
+        assert_actual(2023, 12, Part::Two, Day12::p2);
+    }
+}
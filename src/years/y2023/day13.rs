@@ -0,0 +1,222 @@
+use crate::{grid::Grid, parse::ParseError, rng::Rng, Solution};
+
+/// How many cells differ between `pattern` and its mirror image across the
+/// line between rows `line - 1` and `line`, ignoring rows mirrored outside.
+fn reflection_errors(pattern: &Grid<bool>, line: usize) -> usize {
+    (0..line)
+        .rev()
+        .zip(line..pattern.height())
+        .map(|(above, below)| {
+            pattern
+                .row(above)
+                .iter()
+                .zip(pattern.row(below))
+                .filter(|(a, b)| a != b)
+                .count()
+        })
+        .sum()
+}
+
+/// The number of rows above the horizontal reflection line with exactly
+/// `errors` mismatching cells.
+fn find_reflection_line(pattern: &Grid<bool>, errors: usize) -> Option<usize> {
+    (1..pattern.height()).find(|line| reflection_errors(pattern, *line) == errors)
+}
+
+/// The summary of the reflection line with exactly `errors` mismatching
+/// cells: columns to its left, or 100 times the rows above it.
+fn summarize(pattern: &Grid<bool>, errors: usize) -> Option<usize> {
+    find_reflection_line(&pattern.transpose(), errors)
+        .or_else(|| find_reflection_line(pattern, errors).map(|rows| rows * 100))
+}
+
+/// The summaries of every pattern added up, or which one has no such line,
+/// counting from 1.
+fn summarize_all(patterns: &[Grid<bool>], errors: usize) -> Result<usize, String> {
+    patterns
+        .iter()
+        .enumerate()
+        .map(|(index, pattern)| {
+            summarize(pattern, errors).ok_or_else(|| {
+                format!(
+                    "pattern {} has no reflection line with {} mismatching cells",
+                    index + 1,
+                    errors
+                )
+            })
+        })
+        .sum()
+}
+
+pub struct Day13;
+
+impl Solution for Day13 {
+    type Parsed = Vec<Grid<bool>>;
+    type Answer1 = Result<usize, String>;
+    type Answer2 = Result<usize, String>;
+
+    fn parse(input: &str) -> Result<Self::Parsed, ParseError> {
+        input
+            .trim()
+            .split("\n\n")
+            .map(|pattern| {
+                Grid::parse(input, pattern.trim(), "'#' or '.'", |ch| match ch {
+                    '#' => Some(true),
+                    '.' => Some(false),
+                    _ => None,
+                })
+            })
+            .collect()
+    }
+
+    fn part1(patterns: &Self::Parsed) -> Self::Answer1 {
+        summarize_all(patterns, 0)
+    }
+
+    fn part2(patterns: &Self::Parsed) -> Self::Answer2 {
+        summarize_all(patterns, 1)
+    }
+
+    /// `size` patterns mirrored both across a vertical line, which part 1
+    /// finds, and across a horizontal one, which a smudge outside the
+    /// vertical mirror hides until part 2.
+    fn generate(rng: &mut Rng, size: usize) -> Option<String> {
+        let patterns = (0..size).map(|_| {
+            let (width, height) = (rng.range(5..18) as usize, rng.range(5..18) as usize);
+            let columns = rng.range(1..(width as u64).div_ceil(2)) as usize;
+            let rows = rng.range(1..height as u64) as usize;
+
+            // The cell each one is a mirror image of, across either line.
+            let mirror = |position: usize, line: usize, length: usize| match 2 * line {
+                double if position < double && double - 1 - position < length => {
+                    position.min(double - 1 - position)
+                }
+                _ => position,
+            };
+            let original = (0..width * height)
+                .map(|_| rng.chance(1, 2))
+                .collect::<Vec<_>>();
+            let mut pattern = Grid::from_rows(
+                (0..height)
+                    .map(|y| {
+                        (0..width)
+                            .map(|x| {
+                                let (x, y) = (mirror(x, columns, width), mirror(y, rows, height));
+                                original[y * width + x]
+                            })
+                            .collect()
+                    })
+                    .collect(),
+            );
+
+            let x = rng.range(2 * columns as u64..width as u64) as usize;
+            let y = rows + rng.below(rows.min(height - rows));
+            pattern[(x, y)] = !pattern[(x, y)];
+
+            pattern
+                .map(|cell| if *cell { '#' } else { '.' })
+                .to_string()
+        });
+
+        Some(patterns.collect::<Vec<_>>().join("\n"))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{answers::assert_actual, Part};
+
+    const SAMPLE_INPUT: &str = r"
+#.##..##.
+..#.##.#.
+##......#
+##......#
+..#.##.#.
+..##..##.
+#.#.##.#.
+
+#...##..#
+#....#..#
+..##..###
+#####.##.
+#####.##.
+..##..###
+#....#..#
+";
+
+    #[test]
+    fn test_reflection_errors() {
+        let patterns = Day13::parse(SAMPLE_INPUT).unwrap();
+
+        assert_eq!(reflection_errors(&patterns[0].transpose(), 5), 0);
+        assert_eq!(reflection_errors(&patterns[0], 3), 1);
+        assert_eq!(reflection_errors(&patterns[1], 4), 0);
+        assert_eq!(reflection_errors(&patterns[1], 1), 1);
+        assert!(reflection_errors(&patterns[1], 2) > 1);
+    }
+
+    #[test]
+    fn test_p1_sample() {
+        assert_eq!(Day13::p1(SAMPLE_INPUT), "405");
+    }
+
+    const EDGE_CASE_1: &str = r"
+##..###
+##.#.##
+##.##.#
+#######
+";
+    const EDGE_CASE_2: &str = r"
+#####
+#####
+#.#.#
+.####
+...##
+";
+    const EDGE_CASE_3: &str = r"
+#..####
+#.#.###
+#.##.##
+#######
+";
+    const EDGE_CASE_4: &str = r"
+#..###
+#.#.##
+#.##.#
+######
+######
+";
+
+    #[test]
+    fn test_p1_edge_case() {
+        assert_eq!(Day13::p1(EDGE_CASE_1), "1");
+        assert_eq!(Day13::p1(EDGE_CASE_2), "100");
+        assert_eq!(Day13::p1(EDGE_CASE_3), "6");
+        assert_eq!(Day13::p1(EDGE_CASE_4), "400");
+    }
+
+    #[test]
+    fn test_no_reflection() {
+        let patterns = Day13::parse("#.\n..\n\n##\n.#").unwrap();
+        assert_eq!(
+            Day13::part1(&patterns),
+            Err("pattern 1 has no reflection line with 0 mismatching cells".to_string())
+        );
+    }
+
+    #[test]
+    fn test_p1_actual() {
+        assert_actual(2023, 13, Part::One, Day13::p1);
+    }
+
+    #[test]
+    fn test_p2_sample() {
+        assert_eq!(Day13::p2(SAMPLE_INPUT), "400");
+    }
+
+    #[test]
+    fn test_p2_actual() {
+        assert_actual(2023, 13, Part::Two, Day13::p2);
+    }
+}
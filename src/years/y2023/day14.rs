@@ -0,0 +1,318 @@
+use std::{collections::HashSet, fmt};
+
+use crate::{
+    animate::Animator,
+    cycle::{self, Method},
+    grid::Grid,
+    parse::ParseError,
+    rng::Rng,
+    Solution,
+};
+
+pub type World = Grid<Space>;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Space {
+    RoundRock,
+    CubeRock,
+    Empty,
+}
+
+impl Space {
+    fn parse(ch: char) -> Option<Self> {
+        match ch {
+            'O' => Some(Space::RoundRock),
+            '#' => Some(Space::CubeRock),
+            '.' => Some(Space::Empty),
+            _ => None,
+        }
+    }
+}
+
+impl fmt::Display for Space {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Space::RoundRock => "O",
+            Space::CubeRock => "#",
+            Space::Empty => ".",
+        })
+    }
+}
+
+fn parse_input(input: &str) -> Result<World, ParseError> {
+    Grid::parse(input, input.trim(), "'O', '#' or '.'", Space::parse)
+}
+
+fn roll_rocks_north(world: &mut World) {
+    let mut highest = vec![-1; world.width()];
+
+    world.positions().for_each(|(x, y)| match world[(x, y)] {
+        Space::RoundRock => {
+            highest[x] += 1;
+            let final_y = highest[x] as usize;
+            world[(x, y)] = Space::Empty;
+            world[(x, final_y)] = Space::RoundRock;
+        }
+        Space::CubeRock => {
+            highest[x] = y as i32;
+        }
+        Space::Empty => {}
+    });
+}
+
+/// Rolls the rocks north, west, south then east. Each quarter turn
+/// clockwise brings the next of those sides to the top.
+fn roll_one_cycle(world: &mut World) {
+    (0..4).for_each(|_| {
+        roll_rocks_north(world);
+        *world = world.rotate_clockwise();
+    });
+}
+
+fn calculate_load(world: &World) -> usize {
+    world
+        .rows()
+        .rev()
+        .enumerate()
+        .map(|(row_index, row)| {
+            (row_index + 1) * row.iter().filter(|x| **x == Space::RoundRock).count()
+        })
+        .sum()
+}
+
+/// The world after `cycles` spin cycles. The rocks settle into a loop
+/// long before a billion cycles, so most of them are skipped. Worlds are
+/// compared whole: a hash collision would skip to a wrong one.
+fn spin(world: &World, cycles: usize, method: Method) -> World {
+    cycle::nth_state(
+        world.clone(),
+        cycles,
+        method,
+        |world| {
+            let mut world = world.clone();
+            roll_one_cycle(&mut world);
+            world
+        },
+        World::clone,
+    )
+}
+
+pub struct Day14;
+
+impl Solution for Day14 {
+    type Parsed = World;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Parsed, ParseError> {
+        parse_input(input)
+    }
+
+    fn part1(world: &Self::Parsed) -> Self::Answer1 {
+        let mut world = world.clone();
+        roll_rocks_north(&mut world);
+        calculate_load(&world)
+    }
+
+    fn part2(world: &Self::Parsed) -> Self::Answer2 {
+        calculate_load(&spin(world, 1_000_000_000, Method::Hashing))
+    }
+
+    /// The spin cycles of part 2, one tilt per frame, until the rocks
+    /// settle into a loop.
+    fn animate(world: &Self::Parsed, animator: &mut dyn Animator) -> bool {
+        let mut world = world.clone();
+        let mut seen = HashSet::from([world.clone()]);
+
+        if !animator.frame("start", &world) {
+            return true;
+        }
+
+        for cycle in 1.. {
+            for (turn, side) in ["north", "west", "south", "east"].into_iter().enumerate() {
+                roll_rocks_north(&mut world);
+
+                // The side just tilted towards is at the top, so turn the
+                // rest of the way round to show it upright.
+                let upright = (turn..4).fold(world.clone(), |world, _| world.rotate_clockwise());
+                let caption = format!(
+                    "cycle {}, tilted {}, load {}",
+                    cycle,
+                    side,
+                    calculate_load(&upright)
+                );
+                if !animator.frame(&caption, &upright) {
+                    return true;
+                }
+
+                world = world.rotate_clockwise();
+            }
+
+            if !seen.insert(world.clone()) {
+                break;
+            }
+        }
+
+        true
+    }
+
+    /// A `size` by `size` platform with about one round rock in 5 tiles and
+    /// one cube rock in 8.
+    fn generate(rng: &mut Rng, size: usize) -> Option<String> {
+        let rows = (0..size)
+            .map(|_| {
+                (0..size)
+                    .map(|_| match rng.below(40) {
+                        0..=7 => Space::RoundRock,
+                        8..=12 => Space::CubeRock,
+                        _ => Space::Empty,
+                    })
+                    .collect()
+            })
+            .collect();
+
+        Some(World::from_rows(rows).to_string())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{answers::assert_actual, Part};
+
+    const SAMPLE_INPUT: &str = r"
+O....#....
+O.OO#....#
+.....##...
+OO.#O....O
+.O.....O#.
+O.#..O.#.#
+..O..#O..O
+.......O..
+#....###..
+#OO..#....
+";
+
+    #[test]
+    fn test_p1_sample() {
+        assert_eq!(Day14::p1(SAMPLE_INPUT), "136");
+    }
+
+    #[test]
+    fn test_p1_actual() {
+        assert_actual(2023, 14, Part::One, Day14::p1);
+    }
+
+    #[test]
+    fn test_roll_cycles() {
+        let mut world = parse_input(SAMPLE_INPUT).unwrap();
+
+        roll_one_cycle(&mut world);
+        assert_eq!(
+            world.to_string().trim(),
+            r"
+.....#....
+....#...O#
+...OO##...
+.OO#......
+.....OOO#.
+.O#...O#.#
+....O#....
+......OOOO
+#...O###..
+#..OO#....
+"
+            .trim()
+        );
+
+        roll_one_cycle(&mut world);
+        assert_eq!(
+            world.to_string().trim(),
+            r"
+.....#....
+....#...O#
+.....##...
+..O#......
+.....OOO#.
+.O#...O#.#
+....O#...O
+.......OOO
+#..OO###..
+#.OOO#...O
+"
+            .trim()
+        );
+
+        roll_one_cycle(&mut world);
+        assert_eq!(
+            world.to_string().trim(),
+            r"
+.....#....
+....#...O#
+.....##...
+..O#......
+.....OOO#.
+.O#...O#.#
+....O#...O
+.......OOO
+#...O###.O
+#.OOO#...O
+"
+            .trim()
+        );
+    }
+
+    #[test]
+    fn test_spin() {
+        let world = parse_input(SAMPLE_INPUT).unwrap();
+        let mut rolled = world.clone();
+        (0..3).for_each(|_| roll_one_cycle(&mut rolled));
+
+        assert_eq!(spin(&world, 3, Method::Hashing), rolled);
+        assert_eq!(spin(&world, 3, Method::Brent), rolled);
+        assert_eq!(
+            spin(&world, 1_000_000_000, Method::Brent),
+            spin(&world, 1_000_000_000, Method::Hashing)
+        );
+    }
+
+    #[test]
+    fn test_parse_error() {
+        let error = Day14::parse("O.#\n.x.").err().unwrap();
+        assert_eq!((error.line, error.column), (2, 2));
+        assert_eq!(error.found, "x");
+        assert_eq!(error.expected, "'O', '#' or '.'");
+    }
+
+    #[test]
+    fn test_animate() {
+        let world = parse_input(SAMPLE_INPUT).unwrap();
+        let mut frames = vec![];
+        assert!(Day14::animate(&world, &mut frames));
+
+        let mut rolled = world.clone();
+        roll_rocks_north(&mut rolled);
+        assert_eq!(
+            frames[1],
+            format!("cycle 1, tilted north, load 136\n{}", rolled)
+        );
+
+        let mut cycled = world.clone();
+        roll_one_cycle(&mut cycled);
+        assert_eq!(
+            frames[4],
+            format!("cycle 1, tilted east, load 87\n{}", cycled)
+        );
+        assert_eq!(frames.len() % 4, 1);
+    }
+
+    #[test]
+    fn test_p2_sample() {
+        assert_eq!(Day14::p2(SAMPLE_INPUT), "64");
+    }
+
+    #[test]
+    fn test_p2_actual() {
+        assert_actual(2023, 14, Part::Two, Day14::p2);
+    }
+}
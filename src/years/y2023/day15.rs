@@ -1,35 +1,32 @@
 use std::collections::HashMap;
 
-const ACTUAL_INPUT: &str = include_str!("../../../actual_inputs/2023/15/input.txt");
+use crate::{
+    parse::{self, ParseError},
+    rng::Rng,
+    Solution,
+};
 
 fn hash(text: &str) -> u8 {
     text.chars()
         .fold(0u8, |acc, ch| acc.wrapping_add(ch as u8).wrapping_mul(17))
 }
 
-fn p1(input: &str) -> String {
-    input
-        .trim()
-        .split(',')
-        .map(hash)
-        .map(|val| val as u64)
-        .sum::<u64>()
-        .to_string()
-}
-
 #[derive(Debug)]
-enum Step {
+pub enum Step {
     Remove(String),
     Add(String, i32),
 }
 
 impl Step {
-    fn parse(step: &str) -> Self {
-        if step.ends_with('-') {
-            Self::Remove(step.chars().take(step.len() - 1).collect())
+    fn parse(input: &str, step: &str) -> Result<Self, ParseError> {
+        if let Some(label) = step.strip_suffix('-') {
+            Ok(Self::Remove(label.to_string()))
         } else {
-            let (label, focal_length) = step.split_once('=').unwrap();
-            Self::Add(label.to_string(), focal_length.parse().unwrap())
+            let (label, focal_length) = parse::split_once(input, step, "=")?;
+            Ok(Self::Add(
+                label.to_string(),
+                parse::number(input, focal_length)?,
+            ))
         }
     }
 }
@@ -162,55 +159,104 @@ impl BoxContentCollection {
     }
 }
 
-fn p2(input: &str) -> String {
-    input
-        .trim()
-        .split(',')
-        .map(Step::parse)
-        .fold(BoxContentCollection::new(), |mut acc, step| {
-            match step {
-                Step::Remove(label) => {
-                    acc.remove(&label);
+pub struct Day15;
+
+impl Solution for Day15 {
+    type Parsed = Vec<(String, Step)>;
+    type Answer1 = u64;
+    type Answer2 = i32;
+
+    fn parse(input: &str) -> Result<Self::Parsed, ParseError> {
+        input
+            .trim()
+            .split(',')
+            .map(|step| Ok((step.to_string(), Step::parse(input, step)?)))
+            .collect()
+    }
+
+    fn part1(steps: &Self::Parsed) -> Self::Answer1 {
+        steps
+            .iter()
+            .map(|(step, _)| hash(step))
+            .map(|val| val as u64)
+            .sum::<u64>()
+    }
+
+    fn part2(steps: &Self::Parsed) -> Self::Answer2 {
+        steps
+            .iter()
+            .fold(BoxContentCollection::new(), |mut acc, (_, step)| {
+                match step {
+                    Step::Remove(label) => {
+                        acc.remove(label);
+                    }
+                    Step::Add(label, focal_length) => {
+                        acc.set(label, *focal_length);
+                    }
                 }
-                Step::Add(label, focal_length) => {
-                    acc.set(&label, focal_length);
+                acc
+            })
+            .calculate_focusing_power()
+    }
+
+    /// `size` steps on a pool of labels small enough that lenses get
+    /// replaced and removed again.
+    fn generate(rng: &mut Rng, size: usize) -> Option<String> {
+        let labels = (0..size / 4 + 1)
+            .map(|_| {
+                (0..rng.range(2..7))
+                    .map(|_| (b'a' + rng.below(26) as u8) as char)
+                    .collect::<String>()
+            })
+            .collect::<Vec<_>>();
+
+        let steps = (0..size)
+            .map(|_| {
+                let label = rng.choose(&labels);
+                if rng.chance(1, 4) {
+                    format!("{}-", label)
+                } else {
+                    format!("{}={}", label, rng.range(1..10))
                 }
-            }
-            acc
-        })
-        .calculate_focusing_power()
-        .to_string()
-}
+            })
+            .collect::<Vec<_>>();
 
-fn main() {
-    println!("{}", p1(ACTUAL_INPUT));
-    println!("{}", p2(ACTUAL_INPUT));
+        Some(steps.join(",") + "\n")
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::{answers::assert_actual, Part};
 
     const SAMPLE_INPUT: &str = "rn=1,cm-,qp=3,cm=2,qp-,pc=4,ot=9,ab=5,pc-,pc=6,ot=7";
 
     #[test]
     fn test_p1_sample() {
-        assert_eq!(p1("HASH"), "52");
-        assert_eq!(p1(SAMPLE_INPUT), "1320");
+        assert_eq!(hash("HASH"), 52);
+        assert_eq!(Day15::p1(SAMPLE_INPUT), "1320");
     }
 
     #[test]
     fn test_p1_actual() {
-        assert_eq!(p1(ACTUAL_INPUT), "507291");
+        assert_actual(2023, 15, Part::One, Day15::p1);
+    }
+
+    #[test]
+    fn test_parse_error() {
+        let error = Day15::parse("rn=1,cm-,qp3").err().unwrap();
+        assert_eq!((error.line, error.column), (1, 13));
+        assert_eq!(error.expected, "\"=\"");
     }
 
     #[test]
     fn test_p2_sample() {
-        assert_eq!(p2(SAMPLE_INPUT), "145");
+        assert_eq!(Day15::p2(SAMPLE_INPUT), "145");
     }
 
     #[test]
     fn test_p2_actual() {
-        assert_eq!(p2(ACTUAL_INPUT), "296921");
+        assert_actual(2023, 15, Part::Two, Day15::p2);
     }
 }
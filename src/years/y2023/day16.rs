@@ -0,0 +1,276 @@
+use std::collections::HashSet;
+
+use crate::{
+    animate::Animator,
+    dir::Dir4,
+    grid::Grid,
+    parse::ParseError,
+    render::{Canvas, Color},
+    rng::Rng,
+    vec2::Vec2,
+    Solution,
+};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+struct Ray {
+    pos: Vec2,
+    direction: Dir4,
+}
+
+impl Ray {
+    fn towards(&self, direction: Dir4) -> Self {
+        Self {
+            pos: self.pos.step(direction),
+            direction,
+        }
+    }
+
+    fn move_ray(&self, map: &Grid<char>) -> Vec<Ray> {
+        let pos_ch = map[map.checked_pos(self.pos).unwrap()];
+        let direction = self.direction;
+
+        let next_directions = match pos_ch {
+            '.' => vec![direction],
+            '-' if direction.is_horizontal() => vec![direction],
+            '|' if !direction.is_horizontal() => vec![direction],
+            '-' | '|' => vec![direction.turn_left(), direction.turn_right()],
+            '/' if direction.is_horizontal() => vec![direction.turn_left()],
+            '/' => vec![direction.turn_right()],
+            '\\' if direction.is_horizontal() => vec![direction.turn_right()],
+            '\\' => vec![direction.turn_left()],
+            _ => unreachable!(),
+        };
+
+        next_directions
+            .into_iter()
+            .map(|direction| self.towards(direction))
+            .filter(|ray| map.checked_pos(ray.pos).is_some())
+            .collect()
+    }
+}
+
+/// The beam as it spreads: the rays that just moved, and every ray seen so
+/// far.
+struct Beam {
+    rays: Vec<Ray>,
+    visited: HashSet<Ray>,
+}
+
+impl Beam {
+    fn new(start_ray: Ray) -> Self {
+        Self {
+            rays: vec![start_ray],
+            visited: HashSet::from_iter([start_ray]),
+        }
+    }
+
+    /// Moves every ray one tile. Returns `false` once no ray is left that
+    /// goes somewhere new.
+    fn step(&mut self, map: &Grid<char>) -> bool {
+        self.rays = self
+            .rays
+            .iter()
+            .flat_map(|ray| ray.move_ray(map))
+            .filter(|ray| !self.visited.contains(ray))
+            .collect();
+        self.visited.extend(self.rays.iter().copied());
+
+        !self.rays.is_empty()
+    }
+
+    fn energized(&self) -> HashSet<Vec2> {
+        self.visited.iter().map(|ray| ray.pos).collect()
+    }
+
+    /// The map with energized tiles as `#`, and the rays that just moved
+    /// as arrows.
+    fn to_grid(&self, map: &Grid<char>) -> Grid<char> {
+        let mut grid = map.clone();
+        self.energized().into_iter().for_each(|pos| {
+            let tile = &mut grid[map.checked_pos(pos).unwrap()];
+            if *tile == '.' {
+                *tile = '#';
+            }
+        });
+        self.rays.iter().for_each(|ray| {
+            grid[map.checked_pos(ray.pos).unwrap()] = match ray.direction {
+                Dir4::Up => '^',
+                Dir4::Right => '>',
+                Dir4::Down => 'v',
+                Dir4::Left => '<',
+            };
+        });
+
+        grid
+    }
+}
+
+/// Every tile a beam passes through, starting from `start_ray`.
+fn energized(map: &Grid<char>, start_ray: Ray) -> HashSet<Vec2> {
+    let mut beam = Beam::new(start_ray);
+    while beam.step(map) {}
+
+    beam.energized()
+}
+
+fn parse_map(input: &str) -> Result<Grid<char>, ParseError> {
+    Grid::parse(input, input.trim(), "one of ./\\|-", |ch| {
+        matches!(ch, '.' | '/' | '\\' | '|' | '-').then_some(ch)
+    })
+}
+
+/// The beam of part 1, entering the top-left corner heading right.
+const START: Ray = Ray {
+    pos: Vec2::ZERO,
+    direction: Dir4::Right,
+};
+
+pub struct Day16;
+
+impl Solution for Day16 {
+    type Parsed = Grid<char>;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Parsed, ParseError> {
+        parse_map(input)
+    }
+
+    fn part1(map: &Self::Parsed) -> Self::Answer1 {
+        energized(map, START).len()
+    }
+
+    fn part2(map: &Self::Parsed) -> Self::Answer2 {
+        (0..map.width())
+            .flat_map(|x| {
+                vec![
+                    Ray {
+                        pos: Vec2::new(x as i64, 0),
+                        direction: Dir4::Down,
+                    },
+                    Ray {
+                        pos: Vec2::new(x as i64, map.height() as i64 - 1),
+                        direction: Dir4::Up,
+                    },
+                ]
+            })
+            .chain((0..map.height()).flat_map(|y| {
+                vec![
+                    Ray {
+                        pos: Vec2::new(0, y as i64),
+                        direction: Dir4::Right,
+                    },
+                    Ray {
+                        pos: Vec2::new(map.width() as i64 - 1, y as i64),
+                        direction: Dir4::Left,
+                    },
+                ]
+            }))
+            .map(|ray| energized(map, ray).len())
+            .max()
+            .unwrap()
+    }
+
+    /// The beam of part 1 spreading one tile per frame.
+    fn animate(map: &Self::Parsed, animator: &mut dyn Animator) -> bool {
+        let mut beam = Beam::new(START);
+
+        for step in 0.. {
+            let caption = format!("step {}, {} tiles energized", step, beam.energized().len());
+            if !animator.frame(&caption, &beam.to_grid(map)) || !beam.step(map) {
+                break;
+            }
+        }
+
+        true
+    }
+
+    fn render(map: &Self::Parsed) -> Option<Canvas> {
+        let mut canvas = Canvas::from_grid(map, |tile| match tile {
+            '.' => Color::WHITE,
+            _ => Color::grey(90),
+        });
+
+        energized(map, START).into_iter().for_each(|pos| {
+            let tile = map[map.checked_pos(pos).unwrap()];
+            canvas.fill(
+                pos,
+                if tile == '.' {
+                    Color::YELLOW
+                } else {
+                    Color::RED
+                },
+            );
+        });
+
+        Some(canvas)
+    }
+
+    /// A `size` by `size` contraption with a mirror or splitter in about one
+    /// tile in 10.
+    fn generate(rng: &mut Rng, size: usize) -> Option<String> {
+        let rows = (0..size)
+            .map(|_| {
+                (0..size)
+                    .map(|_| match rng.below(10) {
+                        0 => *rng.choose(&['/', '\\', '|', '-']),
+                        _ => '.',
+                    })
+                    .collect()
+            })
+            .collect();
+
+        Some(Grid::from_rows(rows).to_string())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{answers::assert_actual, Part};
+
+    const SAMPLE_INPUT: &str = r"
+.|...\....
+|.-.\.....
+.....|-...
+........|.
+..........
+.........\
+..../.\\..
+.-.-/..|..
+.|....-|.\
+..//.|....
+";
+
+    #[test]
+    fn test_p1_sample() {
+        assert_eq!(Day16::p1(SAMPLE_INPUT), "46");
+    }
+
+    #[test]
+    fn test_p1_actual() {
+        assert_actual(2023, 16, Part::One, Day16::p1);
+    }
+
+    #[test]
+    fn test_animate() {
+        let mut frames = vec![];
+        assert!(Day16::animate(
+            &parse_map(SAMPLE_INPUT).unwrap(),
+            &mut frames
+        ));
+
+        assert!(frames[0].starts_with("step 0, 1 tiles energized\n>|...\\"));
+        assert!(frames.last().unwrap().contains("46 tiles energized"));
+    }
+
+    #[test]
+    fn test_p2_sample() {
+        assert_eq!(Day16::p2(SAMPLE_INPUT), "51");
+    }
+
+    #[test]
+    fn test_p2_actual() {
+        assert_actual(2023, 16, Part::Two, Day16::p2);
+    }
+}
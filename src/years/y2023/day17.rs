@@ -0,0 +1,263 @@
+use crate::{
+    dir::Dir4,
+    grid::Grid,
+    parse::ParseError,
+    render::{Canvas, Color},
+    rng::Rng,
+    search::{self, Path},
+    vec2::Vec2,
+    Solution,
+};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum ProblemPart {
+    Part1,
+    Part2,
+}
+
+impl ProblemPart {
+    fn max_straight_moves(&self) -> usize {
+        match self {
+            ProblemPart::Part1 => 3,
+            ProblemPart::Part2 => 10,
+        }
+    }
+
+    fn min_straight_moves(&self) -> usize {
+        match self {
+            ProblemPart::Part1 => 0,
+            ProblemPart::Part2 => 4,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+struct VirtualNode {
+    pos: Vec2,
+    moves_left: usize,
+    moves_left_direction: Dir4,
+}
+
+impl VirtualNode {
+    fn advance(&self, direction: Dir4, map: &Map, problem: ProblemPart) -> Option<Self> {
+        let pos = self.pos.step(direction);
+
+        if (self.moves_left == 0 && self.moves_left_direction == direction)
+            || self.moves_left_direction.reverse() == direction
+            || (self.moves_left > problem.max_straight_moves() - problem.min_straight_moves()
+                && self.moves_left_direction != direction)
+            || map.map.checked_pos(pos).is_none()
+        {
+            None
+        } else {
+            Some(VirtualNode {
+                pos,
+                moves_left: if self.moves_left_direction == direction {
+                    self.moves_left - 1
+                } else {
+                    problem.max_straight_moves() - 1
+                },
+                moves_left_direction: direction,
+            })
+        }
+    }
+}
+
+#[derive(Debug)]
+pub struct Map {
+    map: Grid<u32>,
+}
+
+impl Map {
+    fn parse_input(input: &str) -> Result<Self, ParseError> {
+        let map = Grid::parse(input, input.trim(), "a digit", |x| x.to_digit(10))?;
+        if map.width() == 0 || map.height() == 0 {
+            return Err(ParseError::at(input, input.trim(), "a digit"));
+        }
+
+        Ok(Self { map })
+    }
+
+    fn get_heat_loss(&self, pos: Vec2) -> u32 {
+        self.map[self.map.checked_pos(pos).unwrap()]
+    }
+
+    fn get_neighbours(&self, node: &VirtualNode, problem: ProblemPart) -> Vec<VirtualNode> {
+        Dir4::ALL
+            .into_iter()
+            .flat_map(|dir| node.advance(dir, self, problem))
+            .collect()
+    }
+}
+
+/// The cheapest path from the top-left to the bottom-right corner, if the
+/// crucible can stop there.
+fn best_path(map: &Map, problem: ProblemPart) -> Option<Path<VirtualNode, u32>> {
+    let goal = Vec2::new(map.map.width() as i64 - 1, map.map.height() as i64 - 1);
+    // Every block costs at least this much, so the heuristic is admissible.
+    let min_heat_loss = map
+        .map
+        .iter()
+        .map(|(_, loss)| *loss)
+        .min()
+        .expect("parse checked for a non-empty grid");
+
+    search::astar(
+        [Dir4::Right, Dir4::Down].map(|direction| VirtualNode {
+            pos: Vec2::ZERO,
+            moves_left: problem.max_straight_moves(),
+            moves_left_direction: direction,
+        }),
+        |node| {
+            map.get_neighbours(node, problem)
+                .into_iter()
+                .map(|node| (node, map.get_heat_loss(node.pos)))
+        },
+        |node| node.pos.manhattan(goal) as u32 * min_heat_loss,
+        |node| {
+            node.pos == goal
+                && node.moves_left <= problem.max_straight_moves() - problem.min_straight_moves()
+        },
+    )
+}
+
+fn solve(map: &Map, problem: ProblemPart) -> Result<u32, String> {
+    best_path(map, problem)
+        .map(|path| path.cost)
+        .ok_or_else(|| "the crucible can't stop at the bottom-right corner".to_string())
+}
+
+pub struct Day17;
+
+impl Solution for Day17 {
+    type Parsed = Map;
+    type Answer1 = Result<u32, String>;
+    type Answer2 = Result<u32, String>;
+
+    fn parse(input: &str) -> Result<Self::Parsed, ParseError> {
+        Map::parse_input(input)
+    }
+
+    fn part1(map: &Self::Parsed) -> Self::Answer1 {
+        solve(map, ProblemPart::Part1)
+    }
+
+    fn part2(map: &Self::Parsed) -> Self::Answer2 {
+        solve(map, ProblemPart::Part2)
+    }
+
+    /// The best paths of both parts over the heat loss of each block, where
+    /// darker blocks lose more heat.
+    fn render(map: &Self::Parsed) -> Option<Canvas> {
+        let mut canvas = Canvas::from_grid(&map.map, |loss| {
+            Color::grey(255 - (*loss).min(9) as u8 * 25)
+        });
+
+        [
+            (ProblemPart::Part1, Color::RED),
+            (ProblemPart::Part2, Color::BLUE),
+        ]
+        .into_iter()
+        .filter_map(|(problem, color)| Some((best_path(map, problem)?, color)))
+        .for_each(|(path, color)| {
+            canvas.stroke(path.states.iter().map(|node| node.pos), color, false);
+        });
+
+        Some(canvas)
+    }
+
+    /// A `size` by `size` map of heat losses from 1 to 9, at least 5 by 5
+    /// so that the ultra crucible of part 2 can get through.
+    fn generate(rng: &mut Rng, size: usize) -> Option<String> {
+        let size = size.max(5);
+        let rows = (0..size)
+            .map(|_| (0..size).map(|_| rng.range(1..10)).collect())
+            .collect();
+
+        Some(Grid::from_rows(rows).to_string())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{answers::assert_actual, Part};
+
+    const SAMPLE_INPUT: &str = r"
+2413432311323
+3215453535623
+3255245654254
+3446585845452
+4546657867536
+1438598798454
+4457876987766
+3637877979653
+4654967986887
+4564679986453
+1224686865563
+2546548887735
+4322674655533
+";
+
+    #[test]
+    fn test_p1_sample() {
+        assert_eq!(Day17::p1(SAMPLE_INPUT), "102");
+    }
+
+    #[test]
+    fn test_no_path() {
+        assert_eq!(Day17::parse("\n").unwrap_err().expected, "a digit");
+
+        // The ultra crucible can't stop after fewer than 4 blocks.
+        let map = Day17::parse("12\n34").unwrap();
+        assert_eq!(Day17::part1(&map), Ok(6));
+        assert_eq!(
+            Day17::part2(&map),
+            Err("the crucible can't stop at the bottom-right corner".to_string())
+        );
+    }
+
+    #[test]
+    fn test_best_path() {
+        let map = Day17::parse(SAMPLE_INPUT).unwrap();
+        let path = best_path(&map, ProblemPart::Part1).unwrap();
+
+        assert_eq!(path.states.first().unwrap().pos, Vec2::ZERO);
+        assert_eq!(path.states.last().unwrap().pos, Vec2::new(12, 12));
+        assert!(path
+            .states
+            .windows(2)
+            .all(|step| step[0].pos.manhattan(step[1].pos) == 1));
+        assert_eq!(
+            path.states[1..]
+                .iter()
+                .map(|node| map.get_heat_loss(node.pos))
+                .sum::<u32>(),
+            path.cost
+        );
+    }
+
+    #[test]
+    fn test_p1_actual() {
+        assert_actual(2023, 17, Part::One, Day17::p1);
+    }
+
+    const SAMPLE_INPUT_2: &str = r"
+111111111111
+999999999991
+999999999991
+999999999991
+999999999991
+";
+
+    #[test]
+    fn test_p2_sample() {
+        assert_eq!(Day17::p2(SAMPLE_INPUT), "94");
+        assert_eq!(Day17::p2(SAMPLE_INPUT_2), "71");
+    }
+
+    #[test]
+    fn test_p2_actual() {
+        assert_actual(2023, 17, Part::Two, Day17::p2);
+    }
+}
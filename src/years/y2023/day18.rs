@@ -0,0 +1,455 @@
+use crate::{
+    dir::Dir4,
+    parse::{self, ParseError},
+    polygon::{Location, Polygon},
+    render::{Canvas, Color},
+    rng::Rng,
+    vec2::Vec2,
+    Solution,
+};
+
+#[derive(Debug, Clone, Copy)]
+enum ProblemPart {
+    Part1,
+    Part2,
+}
+
+fn parse_direction(input: &str, text: &str, problem_part: ProblemPart) -> Result<Dir4, ParseError> {
+    match problem_part {
+        ProblemPart::Part1 => match text {
+            "U" => Ok(Dir4::Up),
+            "D" => Ok(Dir4::Down),
+            "L" => Ok(Dir4::Left),
+            "R" => Ok(Dir4::Right),
+            _ => Err(ParseError::at(input, text, "one of U, D, L or R")),
+        },
+        ProblemPart::Part2 => match text {
+            "0" => Ok(Dir4::Right),
+            "1" => Ok(Dir4::Down),
+            "2" => Ok(Dir4::Left),
+            "3" => Ok(Dir4::Up),
+            _ => Err(ParseError::at(input, text, "a direction digit from 0 to 3")),
+        },
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct Input {
+    dir: Dir4,
+    steps: i64,
+}
+
+impl Input {
+    fn parse_input(input: &str, line: &str, problem_part: ProblemPart) -> Result<Self, ParseError> {
+        let [dir, steps, color] =
+            parse::exactly(input, line, line.split(' '), "direction, steps and color")?;
+
+        match problem_part {
+            ProblemPart::Part1 => {
+                let dir = parse_direction(input, dir, problem_part)?;
+                let steps = parse::number(input, steps)?;
+                Ok(Self { dir, steps })
+            }
+            ProblemPart::Part2 => {
+                let color = color
+                    .strip_prefix("(#")
+                    .and_then(|color| color.strip_suffix(')'))
+                    .filter(|color| color.len() == 6 && color.is_ascii())
+                    .ok_or_else(|| ParseError::at(input, color, "a color like (#70c710)"))?;
+
+                let dir = parse_direction(input, &color[5..], problem_part)?;
+                let steps = i64::from_str_radix(&color[..5], 16)
+                    .map_err(|_| ParseError::at(input, &color[..5], "5 hex digits"))?;
+
+                Ok(Self { dir, steps })
+            }
+        }
+    }
+}
+
+/// How many cubic metres the lagoon holds: the trench itself plus
+/// everything it encloses.
+fn solve(input: &[Input]) -> i64 {
+    Polygon::from_moves(Vec2::ZERO, input.iter().map(|x| (x.dir, x.steps))).lattice_points()
+}
+
+/// The moves around `n` columns side by side, each with a random width up
+/// to `max_width` and spanning from above `limit / 2` to below `limit`.
+/// Every column starts and ends at other heights than its neighbours, so
+/// that there are always `4 * n` moves.
+fn random_lagoon(rng: &mut Rng, n: usize, max_width: u64, limit: u64) -> Vec<(Dir4, i64)> {
+    let mut columns: Vec<(i64, i64, i64)> = vec![];
+    while columns.len() < n {
+        let column = (
+            rng.range(1..max_width + 1) as i64,
+            rng.range(0..limit / 2) as i64,
+            rng.range(limit / 2 + 1..limit) as i64,
+        );
+        match columns.last() {
+            Some(last) if last.1 == column.1 || last.2 == column.2 => continue,
+            _ => columns.push(column),
+        }
+    }
+
+    let vertical = |from: i64, to: i64| {
+        let dir = if to > from { Dir4::Down } else { Dir4::Up };
+        (dir, from.abs_diff(to) as i64)
+    };
+    let mut moves = vec![];
+    columns.iter().enumerate().for_each(|(i, column)| {
+        moves.push((Dir4::Right, column.0));
+        if let Some(next) = columns.get(i + 1) {
+            moves.push(vertical(column.1, next.1));
+        }
+    });
+    let (first, last) = (columns[0], columns[n - 1]);
+    moves.push(vertical(last.1, last.2));
+    columns.iter().enumerate().rev().for_each(|(i, column)| {
+        moves.push((Dir4::Left, column.0));
+        if i > 0 {
+            moves.push(vertical(column.2, columns[i - 1].2));
+        }
+    });
+    moves.push(vertical(first.2, first.1));
+
+    moves
+}
+
+pub struct Day18;
+
+impl Solution for Day18 {
+    type Parsed = (Vec<Input>, Vec<Input>);
+    type Answer1 = i64;
+    type Answer2 = i64;
+
+    fn parse(input: &str) -> Result<Self::Parsed, ParseError> {
+        let parse_plan = |problem_part| {
+            input
+                .trim()
+                .lines()
+                .map(|line| Input::parse_input(input, line, problem_part))
+                .collect::<Result<Vec<_>, _>>()
+        };
+
+        Ok((
+            parse_plan(ProblemPart::Part1)?,
+            parse_plan(ProblemPart::Part2)?,
+        ))
+    }
+
+    fn part1(plans: &Self::Parsed) -> Self::Answer1 {
+        solve(&plans.0)
+    }
+
+    fn part2(plans: &Self::Parsed) -> Self::Answer2 {
+        solve(&plans.1)
+    }
+
+    /// The lagoon of part 1, with the trench around it. Part 2 is far too
+    /// big to draw.
+    fn render(plans: &Self::Parsed) -> Option<Canvas> {
+        let trench = plans.0.iter().fold(vec![Vec2::ZERO], |mut acc, x| {
+            let pos = *acc.last().unwrap();
+            acc.extend((1..=x.steps).map(|steps| pos.step_by(x.dir, steps)));
+            acc
+        });
+
+        let min = Vec2::new(
+            trench.iter().map(|pos| pos.x).min()?,
+            trench.iter().map(|pos| pos.y).min()?,
+        );
+        let max = Vec2::new(
+            trench.iter().map(|pos| pos.x).max()?,
+            trench.iter().map(|pos| pos.y).max()?,
+        );
+        let size = max - min + Vec2::new(1, 1);
+        let mut canvas = Canvas::new(size.x as usize, size.y as usize, Color::WHITE);
+
+        let polygon =
+            Polygon::from_moves(Vec2::ZERO - min, plans.0.iter().map(|x| (x.dir, x.steps)));
+        (0..size.y)
+            .flat_map(|y| (0..size.x).map(move |x| Vec2::new(x, y)))
+            .filter(|pos| polygon.locate(*pos) == Location::Inside)
+            .for_each(|pos| canvas.fill(pos, Color::BLUE));
+        trench
+            .iter()
+            .for_each(|pos| canvas.fill(*pos - min, Color::grey(60)));
+
+        Some(canvas)
+    }
+
+    /// A plan of `4 * size` lines, which dig two unrelated lagoons of
+    /// columns: a small one for part 1, and one too big to draw for part
+    /// 2.
+    fn generate(rng: &mut Rng, size: usize) -> Option<String> {
+        let small = random_lagoon(rng, size, 6, 20);
+        let big = random_lagoon(rng, size, 200_000, 1_000_000);
+
+        let lines = small
+            .into_iter()
+            .zip(big)
+            .map(|((dir, steps), (color_dir, color_steps))| {
+                let letter = match dir {
+                    Dir4::Up => 'U',
+                    Dir4::Down => 'D',
+                    Dir4::Left => 'L',
+                    Dir4::Right => 'R',
+                };
+                let digit = match color_dir {
+                    Dir4::Right => 0,
+                    Dir4::Down => 1,
+                    Dir4::Left => 2,
+                    Dir4::Up => 3,
+                };
+                format!("{} {} (#{:05x}{})\n", letter, steps, color_steps, digit)
+            });
+
+        Some(lines.collect())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashSet;
+
+    use super::*;
+    use crate::{
+        answers::assert_actual,
+        prop::{self, agree},
+        rng::Rng,
+        Part,
+    };
+
+    /// Digs the trench cell by cell and floods the outside, counting what
+    /// is left.
+    fn flood_fill(plan: &[Input]) -> i64 {
+        let trench = plan.iter().fold(vec![Vec2::ZERO], |mut acc, x| {
+            let pos = *acc.last().unwrap();
+            acc.extend((1..=x.steps).map(|steps| pos.step_by(x.dir, steps)));
+            acc
+        });
+        let trench = HashSet::<Vec2>::from_iter(trench);
+
+        // One cell of margin so that the outside is connected.
+        let min = Vec2::new(
+            trench.iter().map(|pos| pos.x).min().unwrap() - 1,
+            trench.iter().map(|pos| pos.y).min().unwrap() - 1,
+        );
+        let max = Vec2::new(
+            trench.iter().map(|pos| pos.x).max().unwrap() + 1,
+            trench.iter().map(|pos| pos.y).max().unwrap() + 1,
+        );
+
+        let mut outside = HashSet::from([min]);
+        let mut queue = vec![min];
+        while let Some(pos) = queue.pop() {
+            Dir4::ALL
+                .into_iter()
+                .map(|dir| pos.step(dir))
+                .filter(|next| (min.x..=max.x).contains(&next.x))
+                .filter(|next| (min.y..=max.y).contains(&next.y))
+                .filter(|next| !trench.contains(next))
+                .for_each(|next| {
+                    if outside.insert(next) {
+                        queue.push(next);
+                    }
+                });
+        }
+
+        (max.x - min.x + 1) * (max.y - min.y + 1) - outside.len() as i64
+    }
+
+    /// A lagoon made of columns side by side, each `(width, top, bottom)`,
+    /// dug clockwise or, if `reversed`, anticlockwise.
+    #[derive(Debug, Clone)]
+    struct Lagoon {
+        columns: Vec<(u64, u64, u64)>,
+        reversed: bool,
+    }
+
+    impl Lagoon {
+        fn generate(rng: &mut Rng) -> Self {
+            let mut columns: Vec<(u64, u64, u64)> = vec![];
+            for _ in 0..rng.range(1..7) {
+                let width = rng.range(1..5);
+                let (top, bottom) = match columns.last() {
+                    None => {
+                        let top = rng.range(0..5);
+                        (top, top + rng.range(1..6))
+                    }
+                    // Overlapping the previous column keeps the trench
+                    // from touching itself.
+                    Some(&(_, previous_top, previous_bottom)) => {
+                        let top = rng.range(0..previous_bottom);
+                        let lowest = top.max(previous_top) + 1;
+                        (top, rng.range(lowest..lowest + 5))
+                    }
+                };
+                columns.push((width, top, bottom));
+            }
+
+            Self {
+                columns,
+                reversed: rng.chance(1, 2),
+            }
+        }
+
+        /// Whether the trench goes around the lagoon without touching
+        /// itself, which shrinking doesn't keep.
+        fn is_valid(&self) -> bool {
+            !self.columns.is_empty()
+                && self
+                    .columns
+                    .iter()
+                    .all(|&(width, top, bottom)| width > 0 && top < bottom)
+                && self.columns.windows(2).all(|pair| {
+                    let ((_, top1, bottom1), (_, top2, bottom2)) = (pair[0], pair[1]);
+                    top1.max(top2) < bottom1.min(bottom2)
+                })
+        }
+
+        fn shrink(&self) -> Vec<Self> {
+            let columns = prop::shrink_vec(&self.columns, |&(width, top, bottom)| {
+                let widths = prop::shrink_number(width)
+                    .into_iter()
+                    .map(|width| (width, top, bottom));
+                let tops = prop::shrink_number(top)
+                    .into_iter()
+                    .map(|top| (width, top, bottom));
+                let bottoms = prop::shrink_number(bottom)
+                    .into_iter()
+                    .map(|bottom| (width, top, bottom));
+                widths.chain(tops).chain(bottoms).collect()
+            })
+            .into_iter()
+            .map(|columns| Self {
+                columns,
+                reversed: self.reversed,
+            });
+            let unreversed = self.reversed.then(|| Self {
+                columns: self.columns.clone(),
+                reversed: false,
+            });
+
+            unreversed
+                .into_iter()
+                .chain(columns)
+                .filter(Lagoon::is_valid)
+                .collect()
+        }
+
+        fn plan(&self) -> Vec<Input> {
+            let mut x = 0;
+            let mut top_side = vec![];
+            let mut bottom_side = vec![];
+            self.columns.iter().for_each(|&(width, top, bottom)| {
+                let (left, right) = (x as i64, (x + width) as i64);
+                top_side.extend([Vec2::new(left, top as i64), Vec2::new(right, top as i64)]);
+                bottom_side.extend([
+                    Vec2::new(left, bottom as i64),
+                    Vec2::new(right, bottom as i64),
+                ]);
+                x += width;
+            });
+
+            let mut corners = top_side
+                .into_iter()
+                .chain(bottom_side.into_iter().rev())
+                .collect::<Vec<_>>();
+            if self.reversed {
+                corners.reverse();
+            }
+
+            let closed = corners.iter().chain(corners.first());
+            corners
+                .iter()
+                .zip(closed.skip(1))
+                .filter(|(from, to)| from != to)
+                .map(|(from, to)| {
+                    let delta = *to - *from;
+                    let unit = Vec2::new(delta.x.signum(), delta.y.signum());
+                    Input {
+                        dir: Dir4::ALL
+                            .into_iter()
+                            .find(|dir| dir.offset() == unit)
+                            .unwrap(),
+                        steps: delta.manhattan(Vec2::ZERO),
+                    }
+                })
+                .collect()
+        }
+    }
+
+    const SAMPLE_INPUT: &str = r"
+R 6 (#70c710)
+D 5 (#0dc571)
+L 2 (#5713f0)
+D 2 (#d2c081)
+R 2 (#59c680)
+D 2 (#411b91)
+L 5 (#8ceee2)
+U 2 (#caa173)
+L 1 (#1b58a2)
+U 2 (#caa171)
+R 2 (#7807d2)
+U 3 (#a77fa3)
+L 2 (#015232)
+U 2 (#7a21e3)
+    ";
+
+    #[test]
+    fn test_p1_sample() {
+        assert_eq!(Day18::p1(SAMPLE_INPUT), "62");
+    }
+
+    #[test]
+    fn test_p1_actual() {
+        assert_actual(2023, 18, Part::One, Day18::p1);
+    }
+
+    #[test]
+    fn test_solve_matches_flood_fill() {
+        prop::check(300, Lagoon::generate, Lagoon::shrink, |lagoon| {
+            let plan = lagoon.plan();
+            agree(solve(&plan), flood_fill(&plan))
+        });
+    }
+
+    #[test]
+    fn test_flood_fill() {
+        let plan = Day18::parse(SAMPLE_INPUT).unwrap().0;
+        assert_eq!(flood_fill(&plan), 62);
+    }
+
+    #[test]
+    fn test_parse_error() {
+        let error = Day18::parse("R 6 (#70c710)\nX 5 (#0dc571)").err().unwrap();
+        assert_eq!((error.line, error.column), (2, 1));
+        assert_eq!(error.found, "X");
+
+        let error = Day18::parse("R 6 (#70c710)\nD 5 (#0dc57)").err().unwrap();
+        assert_eq!((error.line, error.column), (2, 5));
+    }
+
+    #[test]
+    fn test_render() {
+        let canvas = Day18::render(&Day18::parse(SAMPLE_INPUT).unwrap()).unwrap();
+        let dug = (0..canvas.height() as i64)
+            .flat_map(|y| (0..canvas.width() as i64).map(move |x| Vec2::new(x, y)))
+            .filter(|pos| canvas.color(*pos) != Some(Color::WHITE))
+            .count();
+
+        assert_eq!((canvas.width(), canvas.height()), (7, 10));
+        assert_eq!(dug.to_string(), Day18::p1(SAMPLE_INPUT));
+    }
+
+    #[test]
+    fn test_p2_sample() {
+        assert_eq!(Day18::p2(SAMPLE_INPUT), "952408144115");
+    }
+
+    #[test]
+    fn test_p2_actual() {
+        assert_actual(2023, 18, Part::Two, Day18::p2);
+    }
+}
@@ -0,0 +1,700 @@
+use std::{collections::HashMap, ops::Range};
+
+use crate::{
+    interval::IntervalSet,
+    parse::{self, ParseError},
+    rng::Rng,
+    trace::{event, Tracer},
+    Answer, Part, Solution,
+};
+
+/// The possible values of a rating.
+const RATINGS: Range<u64> = 1..4001;
+
+const P_X: usize = 0;
+const P_M: usize = 1;
+const P_A: usize = 2;
+const P_S: usize = 3;
+
+fn part_to_idx(input: &str, part: &str) -> Result<usize, ParseError> {
+    match part {
+        "x" => Ok(P_X),
+        "m" => Ok(P_M),
+        "a" => Ok(P_A),
+        "s" => Ok(P_S),
+        _ => Err(ParseError::at(input, part, "one of x, m, a or s")),
+    }
+}
+
+#[derive(Debug)]
+pub struct Ratings {
+    ratings: [u64; 4],
+}
+
+impl Ratings {
+    fn parse(input: &str, line: &str) -> Result<Self, ParseError> {
+        let line = line.trim();
+        let ratings = line
+            .strip_prefix('{')
+            .and_then(|line| line.strip_suffix('}'))
+            .ok_or_else(|| ParseError::at(input, line, "ratings like {x=1,m=2,a=3,s=4}"))?;
+
+        ratings.split(',').try_fold(
+            Self {
+                ratings: [0, 0, 0, 0],
+            },
+            |mut acc, rating| {
+                let (part, value) = parse::split_once(input, rating, "=")?;
+                let part = part_to_idx(input, part)?;
+                let value = parse::number(input, value)?;
+                acc.ratings[part] = value;
+                Ok(acc)
+            },
+        )
+    }
+}
+
+#[derive(Debug)]
+pub struct Workflow {
+    name: String,
+    ifs: Vec<WorkflowIf>,
+    else_workflow: String,
+}
+
+impl Workflow {
+    fn parse(input: &str, line: &str) -> Result<Self, ParseError> {
+        let (name, rest) = parse::split_once(input, line, "{")?;
+        let rest = rest
+            .strip_suffix('}')
+            .ok_or_else(|| ParseError::at(input, parse::end(rest), "'}'"))?;
+
+        let mut rules = rest.split(',').collect::<Vec<_>>();
+        let else_workflow = rules.pop().unwrap_or_default();
+        if else_workflow.is_empty() || else_workflow.contains(':') {
+            return Err(ParseError::at(
+                input,
+                parse::end(rest),
+                "',' and a workflow for the parts no rule matches",
+            ));
+        }
+
+        Ok(Workflow {
+            name: name.to_string(),
+            ifs: rules
+                .into_iter()
+                .map(|rule| WorkflowIf::parse(input, rule))
+                .collect::<Result<_, _>>()?,
+            else_workflow: else_workflow.to_string(),
+        })
+    }
+
+    /// The workflows the rules of `line`, a valid workflow, send parts to.
+    fn targets(line: &str) -> impl Iterator<Item = &str> {
+        let rules = line.split_once('{').map_or("", |(_, rules)| rules);
+        rules
+            .trim_end_matches('}')
+            .split(',')
+            .map(|rule| rule.rsplit_once(':').map_or(rule, |(_, target)| target))
+    }
+
+    fn execute(&self, rating: &Ratings) -> String {
+        self.ifs
+            .iter()
+            .find(|workflow_if| workflow_if.execute(rating))
+            .map(|workflow_if| workflow_if.true_workflow.to_string())
+            .unwrap_or(self.else_workflow.to_string())
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct Constraints {
+    constraints: [IntervalSet; 4],
+}
+
+impl Constraints {
+    fn combos(&self) -> u64 {
+        self.constraints.iter().map(IntervalSet::count).product()
+    }
+
+    fn apply_constraint(&self, cond: &WorkflowCond) -> Self {
+        let mut result = self.clone();
+        result.constraints[cond.part] = result.constraints[cond.part].intersection(&cond.accepted);
+        result
+    }
+}
+
+#[cfg(test)]
+mod tests_constraints {
+    use super::*;
+
+    fn interval(min: u64, max: u64) -> IntervalSet {
+        IntervalSet::from(min..max + 1)
+    }
+
+    #[test]
+    fn test_constraints_combo() {
+        assert_eq!(
+            Constraints {
+                constraints: [
+                    interval(1, 4000),
+                    interval(1, 4000),
+                    interval(1, 4000),
+                    interval(1, 4000),
+                ],
+            }
+            .combos(),
+            4000 * 4000 * 4000 * 4000
+        );
+        assert_eq!(
+            Constraints {
+                constraints: [
+                    interval(1, 4000),
+                    interval(1000, 4000),
+                    interval(2000, 3500),
+                    interval(1, 10),
+                ],
+            }
+            .combos(),
+            4000 * (4000 - 1000 + 1) * (3500 - 2000 + 1) * (10 - 1 + 1)
+        );
+        assert_eq!(
+            Constraints {
+                constraints: [
+                    IntervalSet::new(),
+                    interval(1000, 4000),
+                    interval(2000, 3500),
+                    interval(1, 10),
+                ],
+            }
+            .combos(),
+            0
+        );
+        assert_eq!(
+            Constraints {
+                constraints: [
+                    interval(1, 4000),
+                    interval(1000, 4000),
+                    interval(2000, 3500),
+                    IntervalSet::new(),
+                ],
+            }
+            .combos(),
+            0
+        );
+    }
+
+    #[test]
+    fn test_constraints_split() {
+        let constraints = Constraints {
+            constraints: [0; 4].map(|_| interval(1, 4000)),
+        };
+        let cond = WorkflowCond {
+            part: P_A,
+            accepted: interval(1000, 1999),
+        };
+
+        assert_eq!(
+            constraints.apply_constraint(&cond.rev()).constraints[P_A],
+            IntervalSet::from_iter([1..1000, 2000..4001])
+        );
+        assert_eq!(
+            constraints.apply_constraint(&cond).combos()
+                + constraints.apply_constraint(&cond.rev()).combos(),
+            constraints.combos()
+        );
+    }
+
+    #[test]
+    fn test_constraints_apply_cond() {
+        assert_eq!(
+            Constraints {
+                constraints: [
+                    interval(1, 4000),
+                    interval(1000, 4000),
+                    interval(2000, 3500),
+                    interval(1, 10),
+                ],
+            }
+            .apply_constraint(&WorkflowCond {
+                part: P_X,
+                accepted: interval(2000, 4000),
+            }),
+            Constraints {
+                constraints: [
+                    interval(2000, 4000),
+                    interval(1000, 4000),
+                    interval(2000, 3500),
+                    interval(1, 10),
+                ],
+            }
+        );
+        assert_eq!(
+            Constraints {
+                constraints: [
+                    interval(1000, 3500),
+                    interval(1000, 4000),
+                    interval(2000, 3500),
+                    interval(1, 10),
+                ],
+            }
+            .apply_constraint(&WorkflowCond {
+                part: P_X,
+                accepted: interval(1, 4000),
+            }),
+            Constraints {
+                constraints: [
+                    interval(1000, 3500),
+                    interval(1000, 4000),
+                    interval(2000, 3500),
+                    interval(1, 10),
+                ],
+            }
+        );
+        assert_eq!(
+            Constraints {
+                constraints: [
+                    interval(1000, 3500),
+                    interval(1000, 4000),
+                    interval(2000, 3500),
+                    interval(1, 10),
+                ],
+            }
+            .apply_constraint(&WorkflowCond {
+                part: P_X,
+                accepted: interval(1, 800),
+            }),
+            Constraints {
+                constraints: [
+                    IntervalSet::new(),
+                    interval(1000, 4000),
+                    interval(2000, 3500),
+                    interval(1, 10),
+                ],
+            }
+        );
+        assert_eq!(
+            Constraints {
+                constraints: [
+                    IntervalSet::new(),
+                    interval(1000, 4000),
+                    interval(2000, 3500),
+                    interval(1, 10),
+                ],
+            }
+            .apply_constraint(&WorkflowCond {
+                part: P_X,
+                accepted: interval(2000, 4000),
+            }),
+            Constraints {
+                constraints: [
+                    IntervalSet::new(),
+                    interval(1000, 4000),
+                    interval(2000, 3500),
+                    interval(1, 10),
+                ],
+            }
+        );
+    }
+}
+
+#[derive(Debug, Clone)]
+struct WorkflowCond {
+    part: usize,
+    accepted: IntervalSet,
+}
+
+impl WorkflowCond {
+    fn parse(input: &str, condition: &str) -> Result<Self, ParseError> {
+        let index = condition
+            .find(['<', '>'])
+            .ok_or_else(|| ParseError::at(input, condition, "a condition like a<2006"))?;
+        let (part, cmp, value) = (
+            &condition[..index],
+            &condition[index..index + 1],
+            &condition[index + 1..],
+        );
+
+        let part = part_to_idx(input, part)?;
+        let accepted = match (cmp, parse::number::<u64>(input, value)?) {
+            ("<", value) if RATINGS.contains(&value) => RATINGS.start..value,
+            (">", value) if RATINGS.contains(&value) => value + 1..RATINGS.end,
+            _ => return Err(ParseError::at(input, value, "a rating between 1 and 4000")),
+        };
+
+        Ok(Self {
+            part,
+            accepted: IntervalSet::from(accepted),
+        })
+    }
+
+    /// The condition matching the ratings this one rejects.
+    fn rev(&self) -> Self {
+        Self {
+            part: self.part,
+            accepted: IntervalSet::from(RATINGS).difference(&self.accepted),
+        }
+    }
+
+    fn execute(&self, rating: &Ratings) -> bool {
+        self.accepted.contains(rating.ratings[self.part])
+    }
+}
+
+#[derive(Debug)]
+struct WorkflowIf {
+    condition: WorkflowCond,
+    true_workflow: String,
+}
+
+impl WorkflowIf {
+    fn parse(input: &str, if_condition: &str) -> Result<Self, ParseError> {
+        let (rest, true_workflow) = parse::split_once(input, if_condition, ":")?;
+
+        Ok(Self {
+            condition: WorkflowCond::parse(input, rest)?,
+            true_workflow: true_workflow.to_string(),
+        })
+    }
+
+    fn execute(&self, rating: &Ratings) -> bool {
+        self.condition.execute(rating)
+    }
+}
+
+fn is_accepted(
+    workflows: &HashMap<String, Workflow>,
+    rating: &Ratings,
+    tracer: &mut dyn Tracer,
+) -> bool {
+    let mut current_workflow = "in".to_string();
+    let mut path = vec![];
+
+    loop {
+        if tracer.enabled() {
+            path.push(current_workflow.clone());
+        }
+
+        if current_workflow == "A" || current_workflow == "R" {
+            let [x, m, a, s] = rating.ratings;
+            event!(
+                tracer,
+                "part",
+                ratings = format!("x={},m={},a={},s={}", x, m, a, s),
+                path = path.join(" -> "),
+            );
+            return current_workflow == "A";
+        }
+
+        current_workflow = workflows[&current_workflow].execute(rating);
+    }
+}
+
+fn accepted_ratings_sum(
+    workflows: &HashMap<String, Workflow>,
+    ratings: &[Ratings],
+    tracer: &mut dyn Tracer,
+) -> u64 {
+    ratings
+        .iter()
+        .filter(|rating| is_accepted(workflows, rating, tracer))
+        .map(|rating| rating.ratings.iter().sum::<u64>())
+        .sum::<u64>()
+}
+
+fn parse_input(input: &str) -> Result<(HashMap<String, Workflow>, Vec<Ratings>), ParseError> {
+    let (workflows, ratings) = parse::split_once(input, input.trim(), "\n\n")?;
+
+    let lines = workflows.trim().lines();
+    let workflows = lines
+        .clone()
+        .map(|line| Workflow::parse(input, line))
+        .map(|workflow| workflow.map(|workflow| (workflow.name.clone(), workflow)))
+        .collect::<Result<HashMap<_, _>, _>>()?;
+
+    if let Some(target) = lines
+        .flat_map(Workflow::targets)
+        .find(|target| !["A", "R"].contains(target) && !workflows.contains_key(*target))
+    {
+        return Err(ParseError::at(input, target, "A, R or a defined workflow"));
+    }
+    if !workflows.contains_key("in") {
+        return Err(ParseError::at(input, input.trim(), "a workflow named in"));
+    }
+
+    let ratings = ratings
+        .trim()
+        .lines()
+        .map(|line| Ratings::parse(input, line))
+        .collect::<Result<Vec<_>, _>>()?;
+
+    Ok((workflows, ratings))
+}
+
+pub struct Day19;
+
+impl Solution for Day19 {
+    type Parsed = (HashMap<String, Workflow>, Vec<Ratings>);
+    type Answer1 = u64;
+    type Answer2 = u64;
+
+    fn parse(input: &str) -> Result<Self::Parsed, ParseError> {
+        parse_input(input)
+    }
+
+    fn part1(input: &Self::Parsed) -> Self::Answer1 {
+        let (workflows, ratings) = input;
+        accepted_ratings_sum(workflows, ratings, &mut ())
+    }
+
+    fn part2(input: &Self::Parsed) -> Self::Answer2 {
+        let (workflows, _) = input;
+
+        fn traverse(
+            workflows: &HashMap<String, Workflow>,
+            current: &str,
+            constraints: &mut Vec<Constraints>,
+        ) -> u64 {
+            if current == "R" {
+                0
+            } else if current == "A" {
+                constraints.last().unwrap().combos()
+            } else {
+                let initial_constraints_len = constraints.len();
+                let workflow = &workflows[current];
+                let mut result = 0;
+
+                workflow
+                    .ifs
+                    .iter()
+                    .enumerate()
+                    .for_each(|(index, if_workflow)| {
+                        assert_eq!(constraints.len(), initial_constraints_len + index);
+
+                        constraints.push(
+                            constraints
+                                .iter()
+                                .last()
+                                .unwrap()
+                                .apply_constraint(&if_workflow.condition),
+                        );
+
+                        result +=
+                            traverse(workflows, if_workflow.true_workflow.as_str(), constraints);
+
+                        assert_eq!(constraints.len(), initial_constraints_len + index + 1);
+
+                        constraints.pop();
+
+                        constraints.push(
+                            constraints
+                                .iter()
+                                .last()
+                                .unwrap()
+                                .apply_constraint(&if_workflow.condition.rev()),
+                        );
+                    });
+
+                assert_eq!(
+                    constraints.len(),
+                    initial_constraints_len + workflow.ifs.len()
+                );
+
+                result += traverse(workflows, workflow.else_workflow.as_str(), constraints);
+
+                assert_eq!(
+                    constraints.len(),
+                    initial_constraints_len + workflow.ifs.len()
+                );
+
+                (0..workflow.ifs.len()).for_each(|_| {
+                    constraints.pop();
+                });
+
+                assert_eq!(constraints.len(), initial_constraints_len);
+
+                result
+            }
+        }
+
+        traverse(
+            workflows,
+            "in",
+            &mut vec![Constraints {
+                constraints: [0; 4].map(|_| IntervalSet::from(RATINGS)),
+            }],
+        )
+    }
+
+    /// Shows the workflows each part goes through. Only part 1 sends parts
+    /// through the workflows.
+    fn explain(
+        input: &Self::Parsed,
+        part: Part,
+        tracer: &mut dyn Tracer,
+    ) -> Option<Result<String, String>> {
+        let (workflows, ratings) = input;
+        match part {
+            Part::One => Some(accepted_ratings_sum(workflows, ratings, tracer).to_answer()),
+            Part::Two => None,
+        }
+    }
+
+    /// `size` workflows and `size` parts. The workflows form a tree, as in
+    /// the actual inputs, which half the time grows from the workflow added
+    /// last to make it deep.
+    fn generate(rng: &mut Rng, size: usize) -> Option<String> {
+        let mut names = vec!["in".to_string()];
+        while names.len() < size {
+            let name = (0..rng.range(2..4))
+                .map(|_| (b'a' + rng.below(26) as u8) as char)
+                .collect::<String>();
+            if !names.contains(&name) {
+                names.push(name);
+            }
+        }
+
+        let mut targets = vec![vec![]; size];
+        (1..size).for_each(|child| {
+            let parent = if rng.chance(1, 2) {
+                child - 1
+            } else {
+                rng.below(child)
+            };
+            targets[parent].push(names[child].clone());
+        });
+
+        let mut workflows = names
+            .iter()
+            .zip(targets)
+            .map(|(name, mut targets)| {
+                while targets.len() < 2 || rng.chance(1, 3) {
+                    targets.push(rng.choose(&["A", "R"]).to_string());
+                }
+                rng.shuffle(&mut targets);
+
+                let otherwise = targets.pop().unwrap();
+                let rules = targets
+                    .into_iter()
+                    .map(|target| {
+                        format!(
+                            "{}{}{}:{}",
+                            rng.choose(&['x', 'm', 'a', 's']),
+                            rng.choose(&['<', '>']),
+                            rng.range(RATINGS),
+                            target
+                        )
+                    })
+                    .collect::<Vec<_>>();
+                format!("{}{{{},{}}}\n", name, rules.join(","), otherwise)
+            })
+            .collect::<Vec<_>>();
+        rng.shuffle(&mut workflows);
+
+        let ratings = (0..size).map(|_| {
+            let [x, m, a, s] = [0; 4].map(|_| rng.range(RATINGS));
+            format!("{{x={},m={},a={},s={}}}\n", x, m, a, s)
+        });
+
+        Some(format!(
+            "{}\n{}",
+            workflows.concat(),
+            ratings.collect::<String>()
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::answers::assert_actual;
+
+    const SAMPLE_INPUT: &str = r"
+px{a<2006:qkq,m>2090:A,rfg}
+pv{a>1716:R,A}
+lnx{m>1548:A,A}
+rfg{s<537:gd,x>2440:R,A}
+qs{s>3448:A,lnx}
+qkq{x<1416:A,crn}
+crn{x>2662:A,R}
+in{s<1351:px,qqz}
+qqz{s>2770:qs,m<1801:hdj,R}
+gd{a>3333:R,R}
+hdj{m>838:A,pv}
+
+{x=787,m=2655,a=1222,s=2876}
+{x=1679,m=44,a=2067,s=496}
+{x=2036,m=264,a=79,s=2244}
+{x=2461,m=1339,a=466,s=291}
+{x=2127,m=1623,a=2188,s=1013}
+    ";
+
+    #[test]
+    fn test_p1_sample() {
+        assert_eq!(Day19::p1(SAMPLE_INPUT), "19114");
+    }
+
+    #[test]
+    fn test_p1_actual() {
+        assert_actual(2023, 19, Part::One, Day19::p1);
+    }
+
+    #[test]
+    fn test_explain() {
+        let input = Day19::parse(SAMPLE_INPUT).unwrap();
+        let mut events = vec![];
+
+        assert_eq!(
+            Day19::explain(&input, Part::One, &mut events),
+            Some(Ok("19114".to_string()))
+        );
+        assert_eq!(
+            events[0].to_string(),
+            "part ratings=x=787,m=2655,a=1222,s=2876 path=in -> qqz -> qs -> lnx -> A"
+        );
+        assert_eq!(
+            events[1].to_string(),
+            "part ratings=x=1679,m=44,a=2067,s=496 path=in -> px -> rfg -> gd -> R"
+        );
+        assert_eq!(events.len(), input.1.len());
+        assert!(Day19::explain(&input, Part::Two, &mut events).is_none());
+    }
+
+    #[test]
+    fn test_parse_error() {
+        let error = Day19::parse("in{x<10:A,y>5:R,A}\n\n{x=1,m=2,a=3,s=4}")
+            .err()
+            .unwrap();
+        assert_eq!((error.line, error.column), (1, 11));
+        assert_eq!(error.expected, "one of x, m, a or s");
+
+        let error = Day19::parse("in{x<10:A,R}\n\n{x=1,m=2,a=3,s=4")
+            .err()
+            .unwrap();
+        assert_eq!((error.line, error.column), (3, 1));
+
+        let error = |input| Day19::parse(input).unwrap_err().to_string();
+        assert_eq!(
+            error("in{a<5:A}\n\n{x=1,m=2,a=3,s=4}"),
+            "line 1, column 9: expected ',' and a workflow for the parts no rule matches, \
+             found nothing"
+        );
+        assert_eq!(
+            error("in{a<5:A,px}\npx{m>1:qs,R}\n\n{x=1,m=2,a=3,s=4}"),
+            "line 2, column 8: expected A, R or a defined workflow, found 'qs'"
+        );
+        assert_eq!(
+            error("px{m>1:A,R}\n\n{x=1,m=2,a=3,s=4}"),
+            "line 1, column 1: expected a workflow named in, found 'px{m>1:A,R}'"
+        );
+    }
+
+    #[test]
+    fn test_p2_sample() {
+        assert_eq!(Day19::p2(SAMPLE_INPUT), "167409079868000");
+    }
+
+    #[test]
+    fn test_p2_actual() {
+        assert_actual(2023, 19, Part::Two, Day19::p2);
+    }
+}
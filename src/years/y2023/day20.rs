@@ -0,0 +1,268 @@
+use crate::{
+    math,
+    parse::{self, ParseError},
+    pulse::{Kind, Network, BROADCASTER},
+    rng::Rng,
+    Solution,
+};
+
+/// How many presses part 2 watches for the inputs of the conjunction
+/// feeding `rx` to come round twice.
+const MAX_PRESSES: u64 = 100_000;
+
+fn parse_network(input: &str) -> Result<Network, ParseError> {
+    let modules = input
+        .trim()
+        .lines()
+        .map(|line| {
+            let (module, outputs) = parse::split_once(input, line, "->")?;
+            let module = module.trim();
+            let (name, kind) = if let Some(name) = module.strip_prefix('%') {
+                (name, Kind::FlipFlop)
+            } else if let Some(name) = module.strip_prefix('&') {
+                (name, Kind::Conjunction)
+            } else if module == BROADCASTER {
+                (module, Kind::Broadcaster)
+            } else {
+                return Err(ParseError::at(
+                    input,
+                    module,
+                    "'%name', '&name' or 'broadcaster'",
+                ));
+            };
+
+            if name.is_empty() || !name.chars().all(|c| c.is_ascii_lowercase()) {
+                return Err(ParseError::at(input, module, "a lowercase name"));
+            }
+
+            let outputs = outputs.split(',').map(str::trim).collect();
+            Ok((name, kind, outputs))
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+
+    Ok(Network::new(modules))
+}
+
+/// For each input of the conjunction feeding `rx`, the first press on which
+/// it sends that conjunction a high pulse, and how many presses later it
+/// does again.
+fn cycles(network: &Network) -> Result<Vec<(u64, u64)>, String> {
+    let hub = match network.index("rx").map(|rx| network.inputs(rx)) {
+        Some(&[hub]) if network.kind(hub) == Kind::Conjunction => hub,
+        _ => return Err("part 2 needs a single conjunction feeding rx".to_string()),
+    };
+
+    let inputs = network.inputs(hub);
+    let mut highs = vec![vec![]; inputs.len()];
+    let mut state = network.initial_state();
+    for press in 1..=MAX_PRESSES {
+        network.press(&mut state, |pulse| {
+            if pulse.to == hub && pulse.high {
+                let slot = inputs
+                    .iter()
+                    .position(|input| *input == pulse.from)
+                    .expect("only the inputs of the hub send it pulses");
+                let highs = &mut highs[slot];
+                if highs.last() != Some(&press) {
+                    highs.push(press);
+                }
+            }
+        });
+
+        if highs.iter().all(|highs| highs.len() >= 2) {
+            return Ok(highs
+                .iter()
+                .map(|highs| (highs[0], highs[1] - highs[0]))
+                .collect());
+        }
+    }
+
+    Err(format!("no cycles within {} presses", MAX_PRESSES))
+}
+
+pub struct Day20;
+
+impl Solution for Day20 {
+    type Parsed = Network;
+    type Answer1 = u64;
+    type Answer2 = Result<u64, String>;
+
+    fn parse(input: &str) -> Result<Self::Parsed, ParseError> {
+        parse_network(input)
+    }
+
+    fn part1(network: &Self::Parsed) -> Self::Answer1 {
+        let mut state = network.initial_state();
+        let (mut low, mut high) = (0, 0);
+        (0..1000).for_each(|_| {
+            network.press(&mut state, |pulse| match pulse.high {
+                true => high += 1,
+                false => low += 1,
+            })
+        });
+
+        low * high
+    }
+
+    fn part2(network: &Self::Parsed) -> Self::Answer2 {
+        // rx gets a low pulse once every input of the conjunction feeding it
+        // sends a high one on the same press. Each input does so on a cycle
+        // of its own, so the first such press solves all their congruences.
+        let cycles = cycles(network)?;
+        let first = cycles.iter().map(|(first, _)| *first).max().unwrap_or(1);
+        let solution = math::crt(
+            cycles
+                .iter()
+                .map(|(first, period)| (first % period, *period)),
+        )
+        .ok_or("the inputs never send high pulses on the same press")?;
+
+        let press = match solution.residue {
+            residue if residue >= first as u128 => residue,
+            residue => {
+                residue + (first as u128 - residue).div_ceil(solution.modulus) * solution.modulus
+            }
+        };
+        press
+            .try_into()
+            .map_err(|_| "rx gets a low pulse after more than u64::MAX presses".to_string())
+    }
+
+    /// `size` counters, at most 5 so that the answer fits in a `u64`, as in
+    /// the actual inputs: each is a chain of 12 flip-flops counting presses
+    /// in binary, and a conjunction that resets it when it reaches a prime.
+    fn generate(rng: &mut Rng, size: usize) -> Option<String> {
+        const BITS: usize = 12;
+
+        let mut primes = (1 << (BITS - 1)..1 << BITS)
+            .filter(|n: &u64| {
+                (2..)
+                    .take_while(|d| d * d <= *n)
+                    .all(|d| !n.is_multiple_of(d))
+            })
+            .collect::<Vec<_>>();
+        rng.shuffle(&mut primes);
+
+        let letter = |index: usize| (b'a' + index as u8) as char;
+        let mut names = (0..26 * 26)
+            .map(|i| format!("{}{}", letter(i / 26), letter(i % 26)))
+            .filter(|name| name != "rx")
+            .collect::<Vec<_>>();
+        rng.shuffle(&mut names);
+
+        let last = names.pop().unwrap();
+        let mut lines = vec![format!("&{} -> rx", last)];
+        let starts = primes[..size.clamp(1, 5)]
+            .iter()
+            .map(|prime| {
+                let bits = (0..BITS).map(|_| names.pop().unwrap()).collect::<Vec<_>>();
+                let (hub, inverter) = (names.pop().unwrap(), names.pop().unwrap());
+
+                // A flip-flop whose bit is set tells the hub, one whose bit
+                // isn't is flipped by it, and so is the first one, which
+                // carries the reset into all the others.
+                let mut hub_outputs = vec![inverter.clone()];
+                bits.iter().enumerate().for_each(|(bit, name)| {
+                    let mut outputs = bits.get(bit + 1).into_iter().cloned().collect::<Vec<_>>();
+                    if prime >> bit & 1 == 1 {
+                        outputs.push(hub.clone());
+                    }
+                    if prime >> bit & 1 == 0 || bit == 0 {
+                        hub_outputs.push(name.clone());
+                    }
+                    rng.shuffle(&mut outputs);
+                    lines.push(format!("%{} -> {}", name, outputs.join(", ")));
+                });
+                rng.shuffle(&mut hub_outputs);
+                lines.push(format!("&{} -> {}", hub, hub_outputs.join(", ")));
+                lines.push(format!("&{} -> {}", inverter, last));
+
+                bits[0].clone()
+            })
+            .collect::<Vec<_>>();
+        lines.push(format!("{} -> {}", BROADCASTER, starts.join(", ")));
+        rng.shuffle(&mut lines);
+
+        Some(lines.join("\n") + "\n")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{answers::assert_actual, Part};
+
+    const SAMPLE_INPUT: &str = r"
+broadcaster -> a, b, c
+%a -> b
+%b -> c
+%c -> inv
+&inv -> a
+";
+    const SAMPLE_INPUT_2: &str = r"
+broadcaster -> a
+%a -> inv, con
+&inv -> b
+%b -> con
+&con -> output
+";
+
+    #[test]
+    fn test_parse() {
+        let network = Day20::parse(SAMPLE_INPUT_2).unwrap();
+        let con = network.index("con").unwrap();
+        assert_eq!(network.kind(con), Kind::Conjunction);
+        assert_eq!(network.inputs(con).len(), 2);
+
+        assert!(Day20::parse("broadcaster -> a\n*a -> b").is_err());
+        assert!(Day20::parse("broadcaster -> a\n%A -> b").is_err());
+        assert!(Day20::parse("broadcaster a").is_err());
+    }
+
+    #[test]
+    fn test_p1_sample() {
+        assert_eq!(Day20::p1(SAMPLE_INPUT), "32000000");
+        assert_eq!(Day20::p1(SAMPLE_INPUT_2), "11687500");
+    }
+
+    #[test]
+    fn test_p1_actual() {
+        assert_actual(2023, 20, Part::One, Day20::p1);
+    }
+
+    #[test]
+    fn test_cycles() {
+        let input = Day20::generate(&mut Rng::new(1), 4).unwrap();
+        let network = Day20::parse(&input).unwrap();
+        let cycles = cycles(&network).unwrap();
+
+        // Every counter fires on the press its prime says, and then again
+        // after as many presses.
+        assert_eq!(cycles.len(), 4);
+        assert!(cycles.iter().all(|(first, period)| first == period));
+        assert_eq!(
+            Day20::part2(&network),
+            Ok(cycles.iter().map(|(_, period)| period).product::<u64>())
+        );
+    }
+
+    #[test]
+    fn test_p2_sample() {
+        // Neither sample has an rx module.
+        let no_hub = Err("part 2 needs a single conjunction feeding rx".to_string());
+        assert_eq!(Day20::part2(&Day20::parse(SAMPLE_INPUT).unwrap()), no_hub);
+        assert_eq!(Day20::part2(&Day20::parse(SAMPLE_INPUT_2).unwrap()), no_hub);
+
+        // The conjunction feeding rx only ever gets low pulses.
+        let network = Day20::parse("broadcaster -> hub\n&hub -> rx").unwrap();
+        assert_eq!(
+            Day20::part2(&network),
+            Err(format!("no cycles within {} presses", MAX_PRESSES))
+        );
+    }
+
+    #[test]
+    fn test_p2_actual() {
+        assert_actual(2023, 20, Part::Two, Day20::p2);
+    }
+}
@@ -0,0 +1,298 @@
+use std::collections::{HashSet, VecDeque};
+
+use crate::{
+    animate::Animator,
+    dir::Dir4,
+    grid::Grid,
+    parse::{self, ParseError},
+    rng::Rng,
+    vec2::Vec2,
+    Solution,
+};
+
+#[derive(Debug)]
+pub struct Input {
+    start: Vec2,
+    rocks: Grid<bool>,
+}
+
+impl Input {
+    fn parse(input: &str) -> Result<Self, ParseError> {
+        let grid = Grid::parse(input, input.trim(), "'.', '#' or 'S'", |ch| {
+            matches!(ch, '.' | '#' | 'S').then_some(ch)
+        })?;
+
+        let start = grid.find(|ch| *ch == 'S').map(Vec2::from).ok_or_else(|| {
+            ParseError::at(input, parse::end(input.trim()), "a starting tile 'S'")
+        })?;
+        let rocks = grid.map(|ch| *ch == '#');
+
+        Ok(Self { start, rocks })
+    }
+}
+
+/// The garden plots reachable with one more step from any of `reached`.
+fn step(input: &Input, reached: &HashSet<Vec2>) -> HashSet<Vec2> {
+    reached
+        .iter()
+        .flat_map(|pos| Dir4::ALL.map(|dir| pos.step(dir)))
+        .filter(|pos| {
+            input
+                .rocks
+                .checked_pos(*pos)
+                .is_some_and(|pos| !input.rocks[pos])
+        })
+        .collect()
+}
+
+fn execute_p1(input: &Input, steps: usize) -> usize {
+    (0..steps)
+        .fold(HashSet::<Vec2>::from_iter([input.start]), |acc, _| {
+            step(input, &acc)
+        })
+        .len()
+}
+
+/// For each of `steps`, the plots reachable in exactly that many steps on
+/// the garden repeated forever in every direction, by walking all of them.
+fn reachable_unbounded(input: &Input, steps: &[usize]) -> Vec<usize> {
+    let radius = steps.iter().copied().max().unwrap_or(0) as i64;
+    let side = 2 * radius + 1;
+    let index = |pos: Vec2| {
+        ((pos.y - input.start.y + radius) * side + pos.x - input.start.x + radius) as usize
+    };
+    let (width, height) = (input.rocks.width() as i64, input.rocks.height() as i64);
+    let is_plot = |pos: Vec2| {
+        !input.rocks[(
+            pos.x.rem_euclid(width) as usize,
+            pos.y.rem_euclid(height) as usize,
+        )]
+    };
+
+    let mut distances = vec![u32::MAX; (side * side) as usize];
+    distances[index(input.start)] = 0;
+    let mut queue = VecDeque::from([input.start]);
+    while let Some(pos) = queue.pop_front() {
+        let distance = distances[index(pos)];
+        Dir4::ALL
+            .map(|dir| pos.step(dir))
+            .into_iter()
+            .filter(|next| next.manhattan(input.start) <= radius && is_plot(*next))
+            .for_each(|next| {
+                if distances[index(next)] == u32::MAX {
+                    distances[index(next)] = distance + 1;
+                    queue.push_back(next);
+                }
+            });
+    }
+
+    // Any plot reached on the way can be returned to by stepping away and
+    // back, as long as the number of steps left is even.
+    steps
+        .iter()
+        .map(|steps| {
+            distances
+                .iter()
+                .filter(|distance| {
+                    **distance as usize <= *steps && **distance as usize % 2 == steps % 2
+                })
+                .count()
+        })
+        .collect()
+}
+
+/// [`reachable_unbounded`] for many steps. Once the steps cover a few
+/// gardens, the walk crosses each new copy along the clear middle row and
+/// column and the clear edges, so every other garden it reaches adds the
+/// same plots again. That makes the count a quadratic in the number of
+/// gardens crossed, for every second one, fitted from three walks.
+fn reachable_tiled(input: &Input, steps: usize) -> usize {
+    let side = input.rocks.width();
+    let (gardens, rest) = (steps / side, steps % side);
+    let first = 2 + gardens % 2;
+    if gardens <= first + 4 {
+        return reachable_unbounded(input, &[steps])[0];
+    }
+
+    let samples = [0, 2, 4].map(|later| rest + (first + later) * side);
+    let counts = reachable_unbounded(input, &samples);
+    let [a, b, c] = [0, 1, 2].map(|i| counts[i] as i64);
+    let n = ((gardens - first) / 2) as i64;
+
+    (a + n * (b - a) + n * (n - 1) / 2 * (c - 2 * b + a)) as usize
+}
+
+/// Whether the garden is laid out like the actual inputs, which
+/// [`reachable_tiled`] relies on.
+fn is_tileable(input: &Input) -> bool {
+    let rocks = &input.rocks;
+    let side = rocks.width();
+    let middle = Vec2::from((side / 2, side / 2));
+    let clear = |pos: (usize, usize)| {
+        let (x, y) = pos;
+        !rocks[pos]
+            || (x != side / 2 && y != side / 2 && x % (side - 1) != 0 && y % (side - 1) != 0)
+    };
+
+    rocks.height() == side && side % 2 == 1 && input.start == middle && rocks.positions().all(clear)
+}
+
+/// The map with rocks as `#` and the plots in `reached` as `O`.
+fn to_grid(input: &Input, reached: &HashSet<Vec2>) -> Grid<char> {
+    let mut grid = input.rocks.map(|rock| if *rock { '#' } else { '.' });
+    reached.iter().for_each(|pos| {
+        grid[input.rocks.checked_pos(*pos).unwrap()] = 'O';
+    });
+
+    grid
+}
+
+pub struct Day21;
+
+impl Solution for Day21 {
+    type Parsed = Input;
+    type Answer1 = usize;
+    type Answer2 = Result<usize, String>;
+
+    fn parse(input: &str) -> Result<Self::Parsed, ParseError> {
+        Input::parse(input)
+    }
+
+    fn part1(input: &Self::Parsed) -> Self::Answer1 {
+        execute_p1(input, 64)
+    }
+
+    /// Walking every plot is out of reach at this many steps, so gardens
+    /// that [`reachable_tiled`] can't handle have no answer.
+    fn part2(input: &Self::Parsed) -> Self::Answer2 {
+        if !is_tileable(input) {
+            return Err(
+                "part 2 needs a square garden with the start in the middle, and no \
+                 rocks in its row, its column or along the edges"
+                    .to_string(),
+            );
+        }
+        Ok(reachable_tiled(input, 26_501_365))
+    }
+
+    /// The plots reachable after each of the 64 steps of part 1.
+    fn animate(input: &Self::Parsed, animator: &mut dyn Animator) -> bool {
+        let mut reached = HashSet::from_iter([input.start]);
+
+        for steps in 0..=64 {
+            if steps > 0 {
+                reached = step(input, &reached);
+            }
+
+            let caption = format!("step {}, {} plots reachable", steps, reached.len());
+            if !animator.frame(&caption, &to_grid(input, &reached)) {
+                break;
+            }
+        }
+
+        true
+    }
+
+    /// A garden of odd side `size` or just above, with the start in the
+    /// middle. As in the actual inputs, its middle row and column, its
+    /// edges and a diamond between them have no rocks.
+    fn generate(rng: &mut Rng, size: usize) -> Option<String> {
+        let half = (size.max(5) / 2) as i64;
+        let rows = (-half..=half)
+            .map(|y| {
+                (-half..=half)
+                    .map(|x| match (x, y) {
+                        (0, 0) => 'S',
+                        _ if x == 0 || y == 0 || x.abs() == half || y.abs() == half => '.',
+                        _ if x.abs() + y.abs() == half => '.',
+                        _ if rng.chance(1, 8) => '#',
+                        _ => '.',
+                    })
+                    .collect()
+            })
+            .collect();
+
+        Some(Grid::from_rows(rows).to_string())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{answers::assert_actual, Part};
+
+    const SAMPLE_INPUT: &str = r"
+...........
+.....###.#.
+.###.##..#.
+..#.#...#..
+....#.#....
+.##..S####.
+.##..#...#.
+.......##..
+.##.#.####.
+.##..##.##.
+...........
+    ";
+
+    #[test]
+    fn test_p1_sample() {
+        assert_eq!(execute_p1(&Input::parse(SAMPLE_INPUT).unwrap(), 6), 16);
+        assert_eq!(Day21::p1(SAMPLE_INPUT), "42");
+    }
+
+    #[test]
+    fn test_p1_actual() {
+        assert_actual(2023, 21, Part::One, Day21::p1);
+    }
+
+    #[test]
+    fn test_animate() {
+        let mut frames = vec![];
+        assert!(Day21::animate(
+            &Input::parse(SAMPLE_INPUT).unwrap(),
+            &mut frames
+        ));
+
+        assert_eq!(frames.len(), 65);
+        assert!(frames[0].starts_with("step 0, 1 plots reachable\n"));
+        assert!(frames[6].starts_with("step 6, 16 plots reachable\n"));
+        assert_eq!(frames[1].matches('O').count(), 2);
+    }
+
+    #[test]
+    fn test_p2_sample() {
+        let input = Input::parse(SAMPLE_INPUT).unwrap();
+        assert_eq!(
+            reachable_unbounded(&input, &[6, 10, 50, 100, 500, 1000]),
+            [16, 50, 1594, 6536, 167004, 668697]
+        );
+
+        // The sample has rocks in the start's row, unlike the actual inputs.
+        assert!(Day21::part2(&input)
+            .unwrap_err()
+            .starts_with("part 2 needs a square garden"));
+    }
+
+    #[test]
+    fn test_reachable_tiled() {
+        (0..3).for_each(|seed| {
+            let input = Day21::parse(&Day21::generate(&mut Rng::new(seed), 11).unwrap()).unwrap();
+            assert!(is_tileable(&input));
+
+            let steps = [5, 16, 93, 104, 115, 126, 130, 137];
+            let tiled = steps.map(|steps| reachable_tiled(&input, steps));
+            assert_eq!(
+                tiled.to_vec(),
+                reachable_unbounded(&input, &steps),
+                "seed {}",
+                seed
+            );
+        });
+    }
+
+    #[test]
+    fn test_p2_actual() {
+        assert_actual(2023, 21, Part::Two, Day21::p2);
+    }
+}
@@ -0,0 +1,330 @@
+use std::collections::VecDeque;
+
+use crate::{
+    grid::Grid,
+    parse::{self, ParseError},
+    rng::Rng,
+    Solution,
+};
+
+/// The largest x or y coordinate accepted. Settling keeps a height for every
+/// column of the area the bricks span, so that area has to stay small.
+const MAX_XY: u32 = 1000;
+
+/// A cuboid of cubes between two corners, both included, with `min` no
+/// greater than `max` along every axis.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Brick {
+    pub min: [u32; 3],
+    pub max: [u32; 3],
+}
+
+impl Brick {
+    fn parse(input: &str, line: &str) -> Result<Self, ParseError> {
+        let corner = |text: &str| -> Result<[u32; 3], ParseError> {
+            let [x, y, z] = parse::exactly(input, text, text.split(','), "a coordinate")?;
+            Ok([
+                parse::number(input, x)?,
+                parse::number(input, y)?,
+                parse::number(input, z)?,
+            ])
+        };
+
+        let (a, b) = parse::split_once(input, line, "~")?;
+        let (a, b) = (corner(a.trim())?, corner(b.trim())?);
+        if a[2] == 0 || b[2] == 0 {
+            return Err(ParseError::at(input, line, "a brick above the ground"));
+        }
+        if [a[0], a[1], b[0], b[1]].iter().any(|&xy| xy > MAX_XY) {
+            let expected = format!("x and y coordinates of at most {MAX_XY}");
+            return Err(ParseError::at(input, line, expected));
+        }
+
+        Ok(Self {
+            min: [0, 1, 2].map(|axis| a[axis].min(b[axis])),
+            max: [0, 1, 2].map(|axis| a[axis].max(b[axis])),
+        })
+    }
+
+    /// The columns the brick covers, seen from above.
+    fn footprint(&self) -> impl Iterator<Item = (usize, usize)> + '_ {
+        (self.min[1]..=self.max[1])
+            .flat_map(move |y| (self.min[0]..=self.max[0]).map(move |x| (x as usize, y as usize)))
+    }
+
+    fn lowered_to(&self, z: u32) -> Self {
+        Self {
+            min: [self.min[0], self.min[1], z],
+            max: [self.max[0], self.max[1], z + self.max[2] - self.min[2]],
+        }
+    }
+}
+
+/// Bricks once they have all fallen as far as they can, with which ones
+/// rest on which. Bricks keep the indices they were given in.
+#[derive(Debug, Clone)]
+pub struct Stack {
+    bricks: Vec<Brick>,
+    supports: Vec<Vec<usize>>,
+    supported_by: Vec<Vec<usize>>,
+}
+
+impl Stack {
+    /// Drops the bricks from the lowest up, keeping the height and the top
+    /// brick of every column.
+    pub fn settle(bricks: &[Brick]) -> Self {
+        let width = bricks.iter().map(|brick| brick.max[0] as usize + 1).max();
+        let height = bricks.iter().map(|brick| brick.max[1] as usize + 1).max();
+        let mut tops = Grid::new(width.unwrap_or(0), height.unwrap_or(0), (0, None::<usize>));
+
+        let mut order = (0..bricks.len()).collect::<Vec<_>>();
+        order.sort_by_key(|index| bricks[*index].min[2]);
+
+        let mut stack = Self {
+            bricks: bricks.to_vec(),
+            supports: vec![vec![]; bricks.len()],
+            supported_by: vec![vec![]; bricks.len()],
+        };
+        order.into_iter().for_each(|index| {
+            let brick = bricks[index];
+            let floor = brick.footprint().map(|pos| tops[pos].0).max().unwrap_or(0);
+
+            let mut below = brick
+                .footprint()
+                .filter_map(|pos| match tops[pos] {
+                    (top, Some(other)) if top == floor => Some(other),
+                    _ => None,
+                })
+                .collect::<Vec<_>>();
+            below.sort();
+            below.dedup();
+            below
+                .iter()
+                .for_each(|other| stack.supports[*other].push(index));
+            stack.supported_by[index] = below;
+
+            let settled = brick.lowered_to(floor + 1);
+            brick
+                .footprint()
+                .for_each(|pos| tops[pos] = (settled.max[2], Some(index)));
+            stack.bricks[index] = settled;
+        });
+
+        stack
+    }
+
+    pub fn bricks(&self) -> &[Brick] {
+        &self.bricks
+    }
+
+    /// The bricks resting directly on `brick`.
+    pub fn supports(&self, brick: usize) -> &[usize] {
+        &self.supports[brick]
+    }
+
+    /// The bricks `brick` rests directly on, none if it's on the ground.
+    pub fn supported_by(&self, brick: usize) -> &[usize] {
+        &self.supported_by[brick]
+    }
+
+    /// Whether taking `brick` away leaves every other brick where it is.
+    pub fn is_safe(&self, brick: usize) -> bool {
+        self.supports[brick]
+            .iter()
+            .all(|above| self.supported_by[*above].len() > 1)
+    }
+
+    /// How many other bricks fall if `brick` is taken away: those whose
+    /// every support falls, one layer after another.
+    pub fn falls_without(&self, brick: usize) -> usize {
+        let mut lost = vec![0; self.bricks.len()];
+        let mut queue = VecDeque::from([brick]);
+        let mut falls = 0;
+
+        while let Some(fallen) = queue.pop_front() {
+            self.supports[fallen].iter().for_each(|above| {
+                lost[*above] += 1;
+                if lost[*above] == self.supported_by[*above].len() {
+                    falls += 1;
+                    queue.push_back(*above);
+                }
+            });
+        }
+
+        falls
+    }
+}
+
+pub struct Day22;
+
+impl Solution for Day22 {
+    type Parsed = Stack;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Parsed, ParseError> {
+        let bricks = input
+            .trim()
+            .lines()
+            .map(|line| Brick::parse(input, line))
+            .collect::<Result<Vec<_>, _>>()?;
+
+        Ok(Stack::settle(&bricks))
+    }
+
+    fn part1(stack: &Self::Parsed) -> Self::Answer1 {
+        (0..stack.bricks().len())
+            .filter(|brick| stack.is_safe(*brick))
+            .count()
+    }
+
+    fn part2(stack: &Self::Parsed) -> Self::Answer2 {
+        (0..stack.bricks().len())
+            .map(|brick| stack.falls_without(brick))
+            .sum()
+    }
+
+    /// `size` bricks of up to 4 cubes over a 10 by 10 area, each dropped a
+    /// few cubes above the ones already under it, so that none overlap.
+    fn generate(rng: &mut Rng, size: usize) -> Option<String> {
+        const AREA: u32 = 10;
+
+        let mut heights = Grid::new(AREA as usize, AREA as usize, 0);
+        let mut lines = (0..size)
+            .map(|_| {
+                let axis = rng.below(3);
+                let length = rng.range(0..4) as u32;
+                let mut max = [0, 1].map(|_| rng.range(0..AREA as u64) as u32);
+                let min = [0, 1].map(|i| match axis == i {
+                    true => max[i].saturating_sub(length),
+                    false => max[i],
+                });
+                max = [0, 1].map(|i| max[i].max(min[i]));
+
+                let brick = Brick {
+                    min: [min[0], min[1], 0],
+                    max: [max[0], max[1], 0],
+                };
+                let floor = brick.footprint().map(|pos| heights[pos]).max().unwrap();
+                let z = floor + 1 + rng.range(0..4) as u32;
+                let top = z + if axis == 2 { length } else { 0 };
+                brick.footprint().for_each(|pos| heights[pos] = top);
+
+                let (a, b) = match rng.chance(1, 2) {
+                    true => (min, max),
+                    false => (max, min),
+                };
+                let (za, zb) = if a == min { (z, top) } else { (top, z) };
+                format!("{},{},{}~{},{},{}\n", a[0], a[1], za, b[0], b[1], zb)
+            })
+            .collect::<Vec<_>>();
+        rng.shuffle(&mut lines);
+
+        Some(lines.concat())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{answers::assert_actual, Part};
+
+    const SAMPLE_INPUT: &str = r"
+1,0,1~1,2,1
+0,0,2~2,0,2
+0,2,3~2,2,3
+0,0,4~0,2,4
+2,0,5~2,2,5
+0,1,6~2,1,6
+1,1,8~1,1,9
+";
+
+    /// Settles the stack again without `brick`, counting what moved.
+    fn falls_by_settling(stack: &Stack, brick: usize) -> usize {
+        let mut others = stack.bricks().to_vec();
+        others.remove(brick);
+        let settled = Stack::settle(&others);
+
+        others
+            .iter()
+            .zip(settled.bricks())
+            .filter(|(before, after)| before != after)
+            .count()
+    }
+
+    #[test]
+    fn test_settle() {
+        let stack = Day22::parse(SAMPLE_INPUT).unwrap();
+        let z = stack.bricks().iter().map(|brick| brick.min[2]);
+        assert_eq!(z.collect::<Vec<_>>(), [1, 2, 2, 3, 3, 4, 5]);
+
+        // A is under B and C, which both hold up D and E.
+        assert_eq!(stack.supported_by(0), []);
+        assert_eq!(stack.supports(0), [1, 2]);
+        assert_eq!(stack.supports(1), [3, 4]);
+        assert_eq!(stack.supported_by(3), [1, 2]);
+        assert_eq!(stack.supports(6), []);
+    }
+
+    #[test]
+    fn test_parse_far_brick() {
+        assert_eq!(
+            Day22::parse("100000000,0,1~100000000,0,1")
+                .unwrap_err()
+                .to_string(),
+            "line 1, column 1: expected x and y coordinates of at most 1000, \
+             found '100000000,0,1~100000000,0,1'"
+        );
+        assert!(Day22::parse("1000,0,1~1000,1000,1").is_ok());
+    }
+
+    #[test]
+    fn test_graph() {
+        let stack = Day22::parse(SAMPLE_INPUT).unwrap();
+        let safe = (0..7).map(|brick| stack.is_safe(brick));
+        assert_eq!(
+            safe.collect::<Vec<_>>(),
+            [false, true, true, true, true, false, true]
+        );
+
+        let falls = (0..7).map(|brick| stack.falls_without(brick));
+        assert_eq!(falls.collect::<Vec<_>>(), [6, 0, 0, 0, 0, 1, 0]);
+    }
+
+    #[test]
+    fn test_falls_match_settling() {
+        (0..5).for_each(|seed| {
+            let input = Day22::generate(&mut Rng::new(seed), 60).unwrap();
+            let stack = Day22::parse(&input).unwrap();
+            (0..stack.bricks().len()).for_each(|brick| {
+                assert_eq!(
+                    stack.falls_without(brick),
+                    falls_by_settling(&stack, brick),
+                    "brick {} of seed {}",
+                    brick,
+                    seed
+                );
+            });
+        });
+    }
+
+    #[test]
+    fn test_p1_sample() {
+        assert_eq!(Day22::p1(SAMPLE_INPUT), "5");
+    }
+
+    #[test]
+    fn test_p1_actual() {
+        assert_actual(2023, 22, Part::One, Day22::p1);
+    }
+
+    #[test]
+    fn test_p2_sample() {
+        assert_eq!(Day22::p2(SAMPLE_INPUT), "7");
+    }
+
+    #[test]
+    fn test_p2_actual() {
+        assert_actual(2023, 22, Part::Two, Day22::p2);
+    }
+}
@@ -0,0 +1,470 @@
+use std::{
+    collections::{HashMap, VecDeque},
+    fmt::Write,
+};
+
+use crate::{
+    dir::Dir4,
+    grid::Grid,
+    parse::ParseError,
+    rng::Rng,
+    trace::{event, Tracer},
+    vec2::Vec2,
+    Answer, Part, Solution,
+};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Tile {
+    Path,
+    Forest,
+    /// Can only be left in this direction, when slopes are respected.
+    Slope(Dir4),
+}
+
+#[derive(Debug)]
+pub struct Map {
+    tiles: Grid<Tile>,
+    start: Vec2,
+    end: Vec2,
+}
+
+impl Map {
+    fn parse(input: &str) -> Result<Self, ParseError> {
+        let tiles = Grid::parse(input, input.trim(), "'.', '#' or a slope", |ch| match ch {
+            '.' => Some(Tile::Path),
+            '#' => Some(Tile::Forest),
+            '^' => Some(Tile::Slope(Dir4::Up)),
+            '>' => Some(Tile::Slope(Dir4::Right)),
+            'v' => Some(Tile::Slope(Dir4::Down)),
+            '<' => Some(Tile::Slope(Dir4::Left)),
+            _ => None,
+        })?;
+
+        let opening = |y: usize, row: &str| {
+            let x = tiles.row(y).iter().position(|tile| *tile == Tile::Path);
+            x.map(|x| Vec2::from((x, y)))
+                .ok_or_else(|| ParseError::at(input, row, "a path through the row"))
+        };
+        let rows = input.trim().lines().collect::<Vec<_>>();
+        let start = opening(0, rows[0])?;
+        let end = opening(tiles.height() - 1, rows[rows.len() - 1])?;
+
+        Ok(Self { tiles, start, end })
+    }
+
+    fn open(&self, pos: Vec2) -> Option<Tile> {
+        let pos = self.tiles.checked_pos(pos)?;
+        (self.tiles[pos] != Tile::Forest).then_some(self.tiles[pos])
+    }
+
+    /// Whether paths meet at `pos`, or it's the start or the end.
+    fn is_junction(&self, pos: Vec2) -> bool {
+        let paths = Dir4::ALL
+            .iter()
+            .filter(|dir| self.open(pos.step(**dir)).is_some())
+            .count();
+        pos == self.start || pos == self.end || paths >= 3
+    }
+
+    /// Follows the path leaving the junction `from` towards `dir` to the
+    /// next junction, giving it and the number of steps, unless the path
+    /// is a dead end or goes up a slope.
+    fn walk(&self, from: Vec2, dir: Dir4, slopes: bool) -> Option<(Vec2, u32)> {
+        let (mut pos, mut dir) = (from, dir);
+        let mut steps = 0;
+
+        loop {
+            if let Some(Tile::Slope(downhill)) = self.open(pos) {
+                if slopes && dir != downhill {
+                    return None;
+                }
+            }
+            let next = pos.step(dir);
+            self.open(next)?;
+            (pos, steps) = (next, steps + 1);
+
+            if self.is_junction(pos) {
+                return Some((pos, steps));
+            }
+            dir = Dir4::ALL
+                .into_iter()
+                .find(|next| *next != dir.reverse() && self.open(pos.step(*next)).is_some())?;
+        }
+    }
+
+    /// The junctions in reading order, so the start is the first and the end
+    /// the last, and the paths between them. Fails with more junctions than
+    /// fit in the bitmask of visited ones.
+    pub fn graph(&self, slopes: bool) -> Result<Graph, String> {
+        let junctions = self
+            .tiles
+            .positions()
+            .map(Vec2::from)
+            .filter(|pos| self.open(*pos).is_some() && self.is_junction(*pos))
+            .collect::<Vec<_>>();
+        if junctions.len() > u64::BITS as usize {
+            return Err(format!(
+                "{} junctions don't fit in a bitmask",
+                junctions.len()
+            ));
+        }
+
+        let indices = junctions
+            .iter()
+            .enumerate()
+            .map(|(index, pos)| (*pos, index))
+            .collect::<HashMap<_, _>>();
+        let edges = junctions
+            .iter()
+            .map(|from| {
+                Dir4::ALL
+                    .into_iter()
+                    .filter_map(|dir| self.walk(*from, dir, slopes))
+                    .filter(|(to, _)| to != from)
+                    .map(|(to, steps)| (indices[&to], steps))
+                    .collect()
+            })
+            .collect();
+
+        Ok(Graph { junctions, edges })
+    }
+}
+
+/// The map with every path between two junctions turned into one edge,
+/// which leaves few enough junctions to try every route between them.
+#[derive(Debug, Clone)]
+pub struct Graph {
+    junctions: Vec<Vec2>,
+    /// The junctions each one leads to, and in how many steps.
+    edges: Vec<Vec<(usize, u32)>>,
+}
+
+impl Graph {
+    pub fn junctions(&self) -> &[Vec2] {
+        &self.junctions
+    }
+
+    pub fn edges(&self, junction: usize) -> &[(usize, u32)] {
+        &self.edges[junction]
+    }
+
+    /// The most steps from the start to the end without going through any
+    /// junction twice, or `None` if the end can't be reached.
+    pub fn longest_path(&self) -> Option<u32> {
+        let end = self.junctions.len() - 1;
+
+        // Once next to an end reached from only one junction, any detour
+        // would have to come back through it, so the end is the only way.
+        let mut last = (0..end).flat_map(|from| {
+            self.edges[from]
+                .iter()
+                .filter(|(to, _)| *to == end)
+                .map(move |(_, steps)| (from, *steps))
+        });
+        let last = match (last.next(), last.next()) {
+            (Some(last), None) => Some(last),
+            _ => None,
+        };
+
+        self.longest_from(0, 0, last)
+    }
+
+    fn longest_from(
+        &self,
+        junction: usize,
+        visited: u64,
+        last: Option<(usize, u32)>,
+    ) -> Option<u32> {
+        if junction == self.junctions.len() - 1 {
+            return Some(0);
+        }
+        if let Some((before_end, steps)) = last {
+            if junction == before_end {
+                return Some(steps);
+            }
+        }
+
+        let visited = visited | 1 << junction;
+        self.edges[junction]
+            .iter()
+            .filter(|(next, _)| visited & 1 << next == 0)
+            .filter_map(|(next, steps)| {
+                self.longest_from(*next, visited, last)
+                    .map(|rest| rest + steps)
+            })
+            .max()
+    }
+
+    /// The graph in Graphviz's DOT language, with junctions labelled by
+    /// their position and edges by their length.
+    pub fn to_dot(&self) -> String {
+        let mut dot = String::from("digraph trails {\n");
+        self.junctions.iter().enumerate().for_each(|(index, pos)| {
+            writeln!(dot, "    j{} [label=\"{},{}\"];", index, pos.x, pos.y).unwrap();
+        });
+        self.edges.iter().enumerate().for_each(|(from, edges)| {
+            edges.iter().for_each(|(to, steps)| {
+                writeln!(dot, "    j{} -> j{} [label={}];", from, to, steps).unwrap();
+            });
+        });
+        dot.push_str("}\n");
+        dot
+    }
+}
+
+fn longest_hike(map: &Map, part: Part, tracer: &mut dyn Tracer) -> Result<u32, String> {
+    let graph = map.graph(part == Part::One)?;
+    event!(tracer, "graph", dot = graph.to_dot());
+    graph
+        .junctions()
+        .iter()
+        .enumerate()
+        .for_each(|(index, pos)| {
+            event!(tracer, "junction", index = index, x = pos.x, y = pos.y);
+            graph.edges(index).iter().for_each(|(to, steps)| {
+                event!(tracer, "edge", from = index, to = to, steps = steps);
+            });
+        });
+
+    graph
+        .longest_path()
+        .ok_or_else(|| "no path to the end".to_string())
+}
+
+pub struct Day23;
+
+impl Solution for Day23 {
+    type Parsed = Map;
+    type Answer1 = Result<u32, String>;
+    type Answer2 = Result<u32, String>;
+
+    fn parse(input: &str) -> Result<Self::Parsed, ParseError> {
+        Map::parse(input)
+    }
+
+    fn part1(map: &Self::Parsed) -> Self::Answer1 {
+        longest_hike(map, Part::One, &mut ())
+    }
+
+    fn part2(map: &Self::Parsed) -> Self::Answer2 {
+        longest_hike(map, Part::Two, &mut ())
+    }
+
+    fn explain(
+        map: &Self::Parsed,
+        part: Part,
+        tracer: &mut dyn Tracer,
+    ) -> Option<Result<String, String>> {
+        Some(longest_hike(map, part, tracer).to_answer())
+    }
+
+    /// A maze about `size` tiles across, with up to 12 loops and its dead
+    /// ends filled in. Every path starts with a slope pointing away from
+    /// the junction closer to the start, so that part 1 can't go in circles.
+    fn generate(rng: &mut Rng, size: usize) -> Option<String> {
+        let side = size.max(7) | 1;
+        let mut tiles = Grid::new(side, side, '#');
+
+        // A random spanning tree of the tiles at odd positions.
+        let mut stack = vec![(1, 1)];
+        tiles[(1, 1)] = '.';
+        while let Some(&(x, y)) = stack.last() {
+            let mut dirs = Dir4::ALL;
+            rng.shuffle(&mut dirs);
+            let next = dirs.into_iter().find_map(|dir| {
+                let next = Vec2::new(x as i64, y as i64).step_by(dir, 2);
+                let pos = tiles.checked_pos(next)?;
+                (tiles[pos] == '#' && pos.0 < side - 1 && pos.1 < side - 1).then_some((
+                    pos,
+                    (x + pos.0) / 2,
+                    (y + pos.1) / 2,
+                ))
+            });
+            match next {
+                Some((pos, wall_x, wall_y)) => {
+                    tiles[pos] = '.';
+                    tiles[(wall_x, wall_y)] = '.';
+                    stack.push(pos);
+                }
+                None => {
+                    stack.pop();
+                }
+            }
+        }
+
+        let mut loops = (size / 2).min(12);
+        for _ in 0..1000 {
+            if loops == 0 {
+                break;
+            }
+            let (x, y) = (1 + rng.below(side - 2), 1 + rng.below(side - 2));
+            if (x + y) % 2 == 1 && tiles[(x, y)] == '#' {
+                tiles[(x, y)] = '.';
+                loops -= 1;
+            }
+        }
+
+        let (start, end) = ((1, 0), (side - 2, side - 1));
+        tiles[start] = '.';
+        tiles[end] = '.';
+        let mut dead_ends = tiles.positions().collect::<VecDeque<_>>();
+        while let Some(pos) = dead_ends.pop_front() {
+            let open = tiles
+                .neighbours4(pos)
+                .filter(|next| tiles[*next] == '.')
+                .collect::<Vec<_>>();
+            if tiles[pos] == '.' && open.len() <= 1 && pos != start && pos != end {
+                tiles[pos] = '#';
+                dead_ends.extend(open);
+            }
+        }
+
+        // Ranking the junctions by their distance from the start.
+        let map = Map::parse(&tiles.to_string()).unwrap();
+        let graph = map
+            .graph(false)
+            .expect("12 loops without dead ends leave few junctions");
+        let mut distances = vec![usize::MAX; graph.junctions().len()];
+        let mut queue = VecDeque::from([(0, 0)]);
+        while let Some((junction, distance)) = queue.pop_front() {
+            if distances[junction] == usize::MAX {
+                distances[junction] = distance;
+                let next = graph
+                    .edges(junction)
+                    .iter()
+                    .map(|(next, _)| (*next, distance + 1));
+                queue.extend(next);
+            }
+        }
+
+        graph
+            .junctions()
+            .iter()
+            .enumerate()
+            .for_each(|(from, pos)| {
+                Dir4::ALL.into_iter().for_each(|dir| {
+                    let Some((to, _)) = map.walk(*pos, dir, false) else {
+                        return;
+                    };
+                    let to = graph
+                        .junctions()
+                        .iter()
+                        .position(|junction| *junction == to);
+                    let first = pos.step(dir);
+                    if (distances[from], from) < (distances[to.unwrap()], to.unwrap())
+                        && !map.is_junction(first)
+                    {
+                        // Pointing the way the path leaves the slope, in
+                        // case it turns there.
+                        let leave = Dir4::ALL.into_iter().find(|next| {
+                            *next != dir.reverse() && map.open(first.step(*next)).is_some()
+                        });
+                        let slope = match leave.unwrap() {
+                            Dir4::Up => '^',
+                            Dir4::Right => '>',
+                            Dir4::Down => 'v',
+                            Dir4::Left => '<',
+                        };
+                        let first = tiles.checked_pos(first).unwrap();
+                        tiles[first] = slope;
+                    }
+                });
+            });
+
+        Some(tiles.to_string())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{answers::assert_actual, Part};
+
+    const SAMPLE_INPUT: &str = r"
+#.#####################
+#.......#########...###
+#######.#########.#.###
+###.....#.>.>.###.#.###
+###v#####.#v#.###.#.###
+###.>...#.#.#.....#...#
+###v###.#.#.#########.#
+###...#.#.#.......#...#
+#####.#.#.#######.#.###
+#.....#.#.#.......#...#
+#.#####.#.#.#########v#
+#.#...#...#...###...>.#
+#.#.#v#######v###.###v#
+#...#.>.#...>.>.#.###.#
+#####v#.#.###v#.#.###.#
+#.....#...#...#.#.#...#
+#.#########.###.#.#.###
+#...###...#...#...#.###
+###.###.#.###v#####v###
+#...#...#.#.>.>.#.>.###
+#.###.###.#.###.#.#v###
+#.....###...###...#...#
+#####################.#
+";
+
+    #[test]
+    fn test_graph() {
+        let map = Day23::parse(SAMPLE_INPUT).unwrap();
+        let graph = map.graph(true).unwrap();
+        assert_eq!(graph.junctions().len(), 9);
+        assert_eq!(graph.junctions()[0], Vec2::new(1, 0));
+        assert_eq!(graph.junctions()[2], Vec2::new(3, 5));
+        assert_eq!(graph.junctions()[8], Vec2::new(21, 22));
+        assert_eq!(graph.edges(0), [(2, 15)]);
+
+        // Without slopes every path goes both ways.
+        let both_ways = map.graph(false).unwrap();
+        let count = |graph: &Graph| (0..9).map(|j| graph.edges(j).len()).sum::<usize>();
+        assert_eq!(count(&both_ways), 2 * count(&graph));
+
+        // All but the corners of an open field are junctions.
+        let field = format!("#.#########\n{}#########.#", "...........\n".repeat(9));
+        let map = Day23::parse(&field).unwrap();
+        assert_eq!(
+            map.graph(true).unwrap_err(),
+            "97 junctions don't fit in a bitmask"
+        );
+        assert_eq!(
+            Day23::part2(&map),
+            Err("97 junctions don't fit in a bitmask".to_string())
+        );
+    }
+
+    #[test]
+    fn test_to_dot() {
+        let map = Day23::parse(SAMPLE_INPUT).unwrap();
+        let dot = map.graph(true).unwrap().to_dot();
+        assert!(dot.starts_with("digraph trails {\n    j0 [label=\"1,0\"];\n"));
+        assert!(dot.contains("    j0 -> j2 [label=15];\n"));
+        assert!(dot.ends_with("}\n"));
+
+        let mut events = vec![];
+        Day23::explain(&map, Part::One, &mut events);
+        assert_eq!(events[0].kind, "graph");
+        assert_eq!(events[0].fields, [("dot", dot)]);
+    }
+
+    #[test]
+    fn test_p1_sample() {
+        assert_eq!(Day23::p1(SAMPLE_INPUT), "94");
+    }
+
+    #[test]
+    fn test_p1_actual() {
+        assert_actual(2023, 23, Part::One, Day23::p1);
+    }
+
+    #[test]
+    fn test_p2_sample() {
+        assert_eq!(Day23::p2(SAMPLE_INPUT), "154");
+    }
+
+    #[test]
+    fn test_p2_actual() {
+        assert_actual(2023, 23, Part::Two, Day23::p2);
+    }
+}
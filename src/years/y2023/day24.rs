@@ -0,0 +1,211 @@
+use std::ops::Range;
+
+use crate::{
+    parse::{self, ParseError},
+    rng::Rng,
+    Solution,
+};
+
+#[derive(Debug, Clone, Copy)]
+struct Vec3f {
+    x: f64,
+    y: f64,
+    z: f64,
+}
+
+impl Vec3f {
+    fn sub(&self, other: &Vec3f) -> Vec3f {
+        Vec3f {
+            x: self.x - other.x,
+            y: self.y - other.y,
+            z: self.z - other.z,
+        }
+    }
+
+    fn dot(&self, other: &Vec3f) -> f64 {
+        self.x * other.x + self.y * other.y + self.z * other.z
+    }
+
+    fn drop_z(&self) -> Vec3f {
+        Vec3f {
+            x: self.x,
+            y: self.y,
+            z: 0.0,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct Hailstone {
+    pos: Vec3f,
+    vel: Vec3f,
+}
+
+impl Hailstone {
+    fn intersects_p1(&self, other: &Hailstone, test_area: &(f64, f64)) -> bool {
+        fn mxc(hailstone: &Hailstone) -> (f64, f64) {
+            let m = hailstone.vel.y / hailstone.vel.x;
+            let c = hailstone.pos.y - m * hailstone.pos.x;
+            (m, c)
+        }
+        let mxc1 = mxc(self);
+        let mxc2 = mxc(other);
+
+        if (mxc1.0 - mxc2.0).abs() < 0.001 {
+            false
+        } else {
+            let det = -mxc1.0 + mxc2.0;
+            let x = (mxc1.1 - mxc2.1) / det;
+            let y = (mxc1.1 * mxc2.0 - mxc2.1 * mxc1.0) / det;
+
+            let intersects =
+                x >= test_area.0 && x <= test_area.1 && y >= test_area.0 && y <= test_area.1;
+
+            if !intersects {
+                false
+            } else {
+                let intersect_pos = Vec3f { x, y, z: 0.0 };
+                let inter1 = intersect_pos.sub(&self.pos.drop_z());
+                let inter2 = intersect_pos.sub(&other.pos.drop_z());
+
+                let dot1 = self.vel.drop_z().dot(&inter1);
+                let dot2 = other.vel.drop_z().dot(&inter2);
+
+                dot1 > 0.0 && dot2 > 0.0
+            }
+        }
+    }
+}
+
+fn parse(input: &str) -> Result<Vec<Hailstone>, ParseError> {
+    fn parse_vec(input: &str, text: &str) -> Result<Vec3f, ParseError> {
+        let [x, y, z] = parse::exactly(input, text, text.split(','), "3 coordinates")?
+            .map(|x| parse::number::<f64>(input, x.trim()));
+        Ok(Vec3f {
+            x: x?,
+            y: y?,
+            z: z?,
+        })
+    }
+
+    input
+        .trim()
+        .lines()
+        .map(|line| {
+            let (pos, vel) = parse::split_once(input, line, "@")?;
+            Ok(Hailstone {
+                pos: parse_vec(input, pos)?,
+                vel: parse_vec(input, vel)?,
+            })
+        })
+        .collect()
+}
+
+fn solve_p1(input: &[Hailstone], test_area: (f64, f64)) -> usize {
+    (0..input.len())
+        .map(|first| {
+            ((first + 1)..input.len())
+                .filter(|second| {
+                    let first = input[first];
+                    let second = input[*second];
+
+                    first.intersects_p1(&second, &test_area)
+                })
+                .count()
+        })
+        .sum::<usize>()
+}
+
+pub struct Day24;
+
+impl Solution for Day24 {
+    type Parsed = Vec<Hailstone>;
+    type Answer1 = usize;
+    type Answer2 = String;
+
+    fn parse(input: &str) -> Result<Self::Parsed, ParseError> {
+        parse(input)
+    }
+
+    fn part1(hailstones: &Self::Parsed) -> Self::Answer1 {
+        solve_p1(hailstones, (200000000000000.0, 400000000000000.0))
+    }
+
+    fn part2(_hailstones: &Self::Parsed) -> Self::Answer2 {
+        "".to_string()
+    }
+
+    /// `size` hailstones around the test area of part 1, all of which a
+    /// rock thrown from somewhere nearby hits at whole nanoseconds, as in
+    /// the actual inputs.
+    fn generate(rng: &mut Rng, size: usize) -> Option<String> {
+        let mut vector = |range: Range<u64>| {
+            [0; 3].map(|_| {
+                let value = rng.range(range.clone()) as i64;
+                value - (range.end / 2) as i64
+            })
+        };
+        let rock = vector(0..200_000_000_000_000).map(|x| x + 300_000_000_000_000);
+        let rock_velocity = vector(0..600);
+
+        let mut times = vec![];
+        while times.len() < size {
+            let time = rng.range(100_000_000_000..1_000_000_000_000) as i64;
+            if !times.contains(&time) {
+                times.push(time);
+            }
+        }
+
+        let hailstones = times.into_iter().map(|time| {
+            let velocity = loop {
+                let velocity = [0; 3].map(|_| rng.range(0..1000) as i64 - 500);
+                if velocity != rock_velocity {
+                    break velocity;
+                }
+            };
+            let position =
+                [0, 1, 2].map(|i| rock[i] + time * rock_velocity[i] - time * velocity[i]);
+            format!(
+                "{}, {}, {} @ {}, {}, {}\n",
+                position[0], position[1], position[2], velocity[0], velocity[1], velocity[2]
+            )
+        });
+
+        Some(hailstones.collect())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{answers::assert_actual, Part};
+
+    const SAMPLE_INPUT: &str = r"
+19, 13, 30 @ -2,  1, -2
+18, 19, 22 @ -1, -1, -2
+20, 25, 34 @ -2, -2, -4
+12, 31, 28 @ -1, -2, -1
+20, 19, 15 @  1, -5, -3
+";
+
+    #[test]
+    fn test_p1_sample() {
+        assert_eq!(solve_p1(&parse(SAMPLE_INPUT).unwrap(), (7.0, 27.0)), 2);
+    }
+
+    #[test]
+    fn test_p1_actual() {
+        assert_actual(2023, 24, Part::One, Day24::p1);
+    }
+
+    #[test]
+    fn test_p2_sample() {
+        assert_eq!(Day24::p2(SAMPLE_INPUT), "");
+    }
+
+    #[test]
+    #[ignore = "not yet implemented"]
+    fn test_p2_actual() {
+        assert_actual(2023, 24, Part::Two, Day24::p2);
+    }
+}
@@ -0,0 +1,307 @@
+use std::collections::{HashMap, VecDeque};
+
+use crate::{
+    parse::{self, ParseError},
+    rng::Rng,
+    Solution,
+};
+
+/// How many wires part 1 expects to cut.
+const WIRES: usize = 3;
+
+/// Components and the wires between them, each wire stored as two arcs,
+/// `2 * wire` one way and `2 * wire + 1` the other.
+#[derive(Debug, Clone)]
+pub struct Wiring {
+    names: Vec<String>,
+    wires: Vec<(usize, usize)>,
+    arcs: Vec<Vec<usize>>,
+}
+
+/// Wires whose removal splits the components in two.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Cut {
+    pub wires: Vec<(usize, usize)>,
+    /// The size of each group, the first one holding component 0.
+    pub sides: (usize, usize),
+}
+
+impl Wiring {
+    fn parse(input: &str) -> Result<Self, ParseError> {
+        let mut indices = HashMap::new();
+        let mut wiring = Self {
+            names: vec![],
+            wires: vec![],
+            arcs: vec![],
+        };
+        let mut index = |wiring: &mut Self, name: &str| -> Result<usize, ParseError> {
+            if name.is_empty() || !name.chars().all(|c| c.is_ascii_lowercase()) {
+                return Err(ParseError::at(input, name, "a lowercase name"));
+            }
+            Ok(*indices.entry(name.to_string()).or_insert_with(|| {
+                wiring.names.push(name.to_string());
+                wiring.arcs.push(vec![]);
+                wiring.names.len() - 1
+            }))
+        };
+
+        for line in input.trim().lines() {
+            let (name, others) = parse::split_once(input, line, ":")?;
+            let from = index(&mut wiring, name.trim())?;
+            for other in others.split_whitespace() {
+                let to = index(&mut wiring, other)?;
+                wiring.arcs[from].push(2 * wiring.wires.len());
+                wiring.arcs[to].push(2 * wiring.wires.len() + 1);
+                wiring.wires.push((from, to));
+            }
+        }
+
+        Ok(wiring)
+    }
+
+    pub fn name(&self, component: usize) -> &str {
+        &self.names[component]
+    }
+
+    /// The components at both ends of `arc`, in its direction.
+    fn ends(&self, arc: usize) -> (usize, usize) {
+        let (a, b) = self.wires[arc / 2];
+        if arc.is_multiple_of(2) {
+            (a, b)
+        } else {
+            (b, a)
+        }
+    }
+
+    /// Edmonds–Karp from `source` to `sink`, with every wire carrying one
+    /// unit either way. Stops once more than `limit` paths are found,
+    /// otherwise gives the components still reachable from `source`, the
+    /// side of the smallest cut between the two.
+    fn max_flow(&self, source: usize, sink: usize, limit: usize) -> Option<Vec<bool>> {
+        let mut flow = vec![0i8; 2 * self.wires.len()];
+
+        for _ in 0..=limit {
+            let mut via = vec![None; self.names.len()];
+            let mut reached = vec![false; self.names.len()];
+            reached[source] = true;
+            let mut queue = VecDeque::from([source]);
+            while let Some(component) = queue.pop_front() {
+                self.arcs[component]
+                    .iter()
+                    .filter(|arc| flow[**arc] < 1)
+                    .for_each(|arc| {
+                        let (_, next) = self.ends(*arc);
+                        if !reached[next] {
+                            reached[next] = true;
+                            via[next] = Some(*arc);
+                            queue.push_back(next);
+                        }
+                    });
+            }
+
+            if !reached[sink] {
+                return Some(reached);
+            }
+            let mut component = sink;
+            while let Some(arc) = via[component] {
+                flow[arc] += 1;
+                flow[arc ^ 1] -= 1;
+                component = self.ends(arc).0;
+            }
+        }
+
+        None
+    }
+
+    /// The smallest cut, if it has at most `limit` wires. Every component
+    /// is on the other side from component 0 for some cut, so the smallest
+    /// of their flows is the smallest overall.
+    pub fn min_cut(&self, limit: usize) -> Option<Cut> {
+        (1..self.names.len())
+            .filter_map(|sink| self.max_flow(0, sink, limit))
+            .map(|side| {
+                let wires = self
+                    .wires
+                    .iter()
+                    .filter(|(a, b)| side[*a] != side[*b])
+                    .copied()
+                    .collect::<Vec<_>>();
+                let size = side.iter().filter(|reached| **reached).count();
+                Cut {
+                    wires,
+                    sides: (size, side.len() - size),
+                }
+            })
+            .min_by_key(|cut| cut.wires.len())
+    }
+}
+
+pub struct Day25;
+
+impl Solution for Day25 {
+    type Parsed = Wiring;
+    type Answer1 = Result<usize, String>;
+    type Answer2 = ();
+
+    fn parse(input: &str) -> Result<Self::Parsed, ParseError> {
+        Wiring::parse(input)
+    }
+
+    fn part1(wiring: &Self::Parsed) -> Self::Answer1 {
+        match wiring.min_cut(WIRES) {
+            Some(cut) if cut.wires.len() == WIRES => Ok(cut.sides.0 * cut.sides.1),
+            Some(cut) => {
+                let wires = cut
+                    .wires
+                    .iter()
+                    .map(|(a, b)| format!("{}/{}", wiring.name(*a), wiring.name(*b)))
+                    .collect::<Vec<_>>();
+                Err(format!(
+                    "cutting {} wires is enough, not {}: {:?}",
+                    cut.wires.len(),
+                    WIRES,
+                    wires
+                ))
+            }
+            None => Err(format!("no {} wires split the components in two", WIRES)),
+        }
+    }
+
+    /// The last day has no part 2: its star is given for the other 49.
+    fn part2(_wiring: &Self::Parsed) -> Self::Answer2 {}
+
+    /// Two groups of about `size / 2` components each, with 3 wires between
+    /// them. Inside a group, each component is wired to the next two in a
+    /// ring and to a few others at random, so no group splits as easily.
+    fn generate(rng: &mut Rng, size: usize) -> Option<String> {
+        let group = (size / 2).max(5);
+        let letter = |index: usize| (b'a' + index as u8) as char;
+        let mut names = (0..26 * 26 * 26)
+            .map(|i| {
+                format!(
+                    "{}{}{}",
+                    letter(i / 676),
+                    letter(i / 26 % 26),
+                    letter(i % 26)
+                )
+            })
+            .collect::<Vec<_>>();
+        rng.shuffle(&mut names);
+
+        let mut wires = vec![];
+        let groups = [0, group].map(|first| {
+            (0..group).for_each(|i| {
+                let ring = [1, 2].map(|step| (first + i, first + (i + step) % group));
+                wires.extend(ring.into_iter().filter(|(a, b)| a != b));
+                if rng.chance(1, 2) {
+                    wires.push((first + i, first + rng.below(group)));
+                }
+            });
+            first..first + group
+        });
+
+        let mut ends = groups.map(|group| {
+            let mut ends = group.collect::<Vec<_>>();
+            rng.shuffle(&mut ends);
+            ends
+        });
+        (0..WIRES).for_each(|_| wires.push((ends[0].pop().unwrap(), ends[1].pop().unwrap())));
+
+        // Every wire only once, on the line of either end.
+        wires
+            .iter_mut()
+            .for_each(|(a, b)| (*a, *b) = (*a.min(b), *a.max(b)));
+        wires.sort();
+        wires.dedup();
+        wires.retain(|(a, b)| a != b);
+        wires.iter_mut().for_each(|(a, b)| {
+            if rng.chance(1, 2) {
+                (*a, *b) = (*b, *a);
+            }
+        });
+
+        let mut lines = vec![vec![]; 2 * group];
+        wires
+            .into_iter()
+            .for_each(|(a, b)| lines[a].push(names[b].clone()));
+        let mut lines = lines
+            .into_iter()
+            .enumerate()
+            .filter(|(_, others)| !others.is_empty())
+            .map(|(a, others)| format!("{}: {}\n", names[a], others.join(" ")))
+            .collect::<Vec<_>>();
+        rng.shuffle(&mut lines);
+
+        Some(lines.concat())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{answers::assert_actual, Part};
+
+    const SAMPLE_INPUT: &str = r"
+jqt: rhn xhk nvd
+rsh: frs pzl lsr
+xhk: hfx
+cmg: qnr nvd lhk bvb
+rhn: xhk bvb hfx
+bvb: xhk hfx
+pzl: lsr hfx nvd
+qnr: nvd
+ntq: jqt hfx bvb xhk
+nvd: lhk
+lsr: lhk
+rzs: qnr cmg lsr rsh
+frs: qnr lhk lsr
+";
+
+    #[test]
+    fn test_min_cut() {
+        let wiring = Day25::parse(SAMPLE_INPUT).unwrap();
+        let cut = wiring.min_cut(WIRES).unwrap();
+
+        let mut wires = cut
+            .wires
+            .iter()
+            .map(|(a, b)| {
+                let mut names = [wiring.name(*a), wiring.name(*b)];
+                names.sort();
+                names.join("/")
+            })
+            .collect::<Vec<_>>();
+        wires.sort();
+        assert_eq!(wires, ["bvb/cmg", "hfx/pzl", "jqt/nvd"]);
+        assert_eq!(cut.sides, (6, 9));
+
+        assert_eq!(wiring.min_cut(2), None);
+    }
+
+    #[test]
+    fn test_p1_cut_too_small() {
+        let wiring = Day25::parse("ab: cd").unwrap();
+        assert_eq!(
+            Day25::part1(&wiring),
+            Err("cutting 1 wires is enough, not 3: [\"ab/cd\"]".to_string())
+        );
+    }
+
+    #[test]
+    fn test_p1_no_cut() {
+        let no_cut = Err("no 3 wires split the components in two".to_string());
+        let wiring = Day25::parse("ab: cd ef gh ij\ncd: ef gh ij\nef: gh ij\ngh: ij").unwrap();
+        assert_eq!(Day25::part1(&wiring), no_cut);
+        assert_eq!(Day25::part1(&Day25::parse("").unwrap()), no_cut);
+    }
+
+    #[test]
+    fn test_p1_sample() {
+        assert_eq!(Day25::p1(SAMPLE_INPUT), "54");
+    }
+
+    #[test]
+    fn test_p1_actual() {
+        assert_actual(2023, 25, Part::One, Day25::p1);
+    }
+}